@@ -15,6 +15,10 @@ impl AuthApi {
         }
     }
 
+    pub fn with_client(client: &'static ApiClient) -> Self {
+        Self { client }
+    }
+
     pub async fn login(&self, email: String, password: String) -> Result<LoginInitResponse> {
         let request = LoginRequest { email, password };
 
@@ -1,7 +1,7 @@
 use crate::client::ApiClient;
 use crate::request_response::user_actions::lead::LeadCreateRequest;
 use crate::request_response::user_actions::lead::LeadCreateResponse;
-use crate::utils::factories::TestDataFactory;
+use crate::utils::request_factories::LeadCreateRequestFactory;
 use anyhow::Result;
 
 pub struct LeadCreateRequestApi {
@@ -15,6 +15,11 @@ impl LeadCreateRequestApi {
         }
     }
 
+    pub fn with_client(client: &'static ApiClient) -> Self {
+        Self { client }
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub async fn create_lead(
         &self,
         country_code: String,
@@ -36,29 +41,23 @@ impl LeadCreateRequestApi {
             campaign_name,
             lead_owner_uuid,
         };
-        let response = self
-            .client
+        self.create_lead_request(request).await
+    }
+
+    pub async fn create_lead_request(
+        &self,
+        request: LeadCreateRequest,
+    ) -> Result<LeadCreateResponse> {
+        self.client
             .post("/api/v1/admin/add-temp-lead")
             .json(&request)
             .send_json::<LeadCreateResponse>()
-            .await;
-
-        response
+            .await
     }
 
     pub async fn create_lead_with_factory(&self) -> Result<LeadCreateResponse> {
-        let config = lib_test_helpers::config::get_config();
-        self.create_lead(
-            config.country_code.clone(),
-            TestDataFactory::name(),
-            TestDataFactory::phone(),
-            TestDataFactory::email(),
-            "test".to_string(),
-            "rust_auto".to_string(),
-            "test".to_string(),
-            "".to_string(),
-        )
-        .await
+        self.create_lead_request(LeadCreateRequestFactory::new().build())
+            .await
     }
 }
 
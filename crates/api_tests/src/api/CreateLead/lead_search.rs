@@ -0,0 +1,54 @@
+use crate::client::ApiClient;
+use crate::request_response::user_actions::lead::LeadSummary;
+use anyhow::{Context, Result};
+use serde_json::Value;
+
+pub struct LeadSearchApi {
+    client: &'static ApiClient,
+}
+
+impl LeadSearchApi {
+    pub fn new() -> Self {
+        Self {
+            client: ApiClient::global(),
+        }
+    }
+
+    pub fn with_client(client: &'static ApiClient) -> Self {
+        Self { client }
+    }
+
+    pub async fn search(&self, query: &str) -> Result<Vec<LeadSummary>> {
+        let response: Value = self
+            .client
+            .get("/api/v1/admin/sales/sales-member/lead-listing")
+            .query(&[("search", query), ("page", "1"), ("limit", "20")])
+            .send_json()
+            .await
+            .context("Lead search failed")?;
+
+        // `data` is either the rows or a page object wrapping them.
+        let data = &response["data"];
+        let rows = ["rows", "data", "leads"]
+            .iter()
+            .find_map(|key| data.get(key))
+            .unwrap_or(data);
+        serde_json::from_value(rows.clone()).context("Unexpected lead listing shape")
+    }
+
+    pub async fn find_by_phone(&self, phone: &str) -> Result<Option<LeadSummary>> {
+        let digits = |s: &str| s.chars().filter(char::is_ascii_digit).collect::<String>();
+        let wanted = digits(phone);
+        Ok(self
+            .search(phone)
+            .await?
+            .into_iter()
+            .find(|lead| digits(&lead.phone).ends_with(&wanted)))
+    }
+}
+
+impl Default for LeadSearchApi {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1 +1,2 @@
 pub mod lead_create;
+pub mod lead_search;
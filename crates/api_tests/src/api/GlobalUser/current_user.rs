@@ -34,3 +34,9 @@ impl CurrentUserApi {
         Ok(response)
     }
 }
+
+impl Default for CurrentUserApi {
+    fn default() -> Self {
+        Self::new()
+    }
+}
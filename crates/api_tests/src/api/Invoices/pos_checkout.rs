@@ -1,8 +1,7 @@
 use crate::api_context::ApiContext;
-use crate::request_response::Invoice_request_response::pos_checkout_request::Items;
-use crate::request_response::Invoice_request_response::pos_checkout_request::Payment;
+use crate::pricing;
 use crate::request_response::Invoice_request_response::pos_checkout_request::PosCheckoutRequest;
-use crate::request_response::Invoice_request_response::pos_checkout_request::ShareInvoice;
+use crate::utils::request_factories::PosCheckoutRequestFactory;
 use crate::ApiClient;
 use anyhow::Result;
 use serde_json::Value;
@@ -40,41 +39,24 @@ impl PosCheckoutApi {
         let cart_items = cart_data["data"]["data"].as_array().unwrap();
         let cart_item_id = cart_items.first().unwrap()["uuid"].as_str().unwrap();
 
-        self.pos_checkout(PosCheckoutRequest {
-            pos_node_id: node_id.to_string(),
-            cart_id: cart_id.to_string(),
-            posReceipt_id: "".to_string(),
-            customer_invoice_name: "".to_string(),
-            cart_items: vec![Items {
-                cart_item_id: cart_item_id.to_string(),
-                discount_percent: 0,
-                description: "".to_string(),
-            }],
-            cart_type: "service".to_string(),
-            concierge_ticket: vec![],
-            payment: Payment {
-                amount: 0.0,
-                method: "link".to_string(),
-                send_link_to_email: "scjakhar78@gmail.com".to_string(),
-                send_link_to_mobile: "8890349935".to_string(),
-            },
-            share_invoice: vec![ShareInvoice {
-                phone_number: "8890349935".to_string(),
-                email: "scjakhar78@gmail.com".to_string(),
-            }],
-            start_date: "2023-01-01".to_string(),
-            end_date: "2023-01-01".to_string(),
-            invoice_date: "2023-01-01".to_string(),
-            invoice_payment_due_date: "2023-01-01".to_string(),
-            place_of_supply: "19971716-d695-4952-8f8e-bb446759ecb9".to_string(),
-            total_discount: 0.0,
-            total_mrp: total_mrp,
-            total_gst: 0.0,
-            bill_total: 0.0,
-            source: "rust_auto".to_string(),
-            lead_uuid: None,
-        })
-        .await?;
+        let request = PosCheckoutRequestFactory::new()
+            .pos_node_id(node_id)
+            .cart_id(cart_id)
+            .cart_item(cart_item_id);
+        let request = match pricing::expected_cart(self.ctx) {
+            Ok(totals) => request.totals(&totals),
+            Err(e) => {
+                println!("Pricing oracle unavailable, sending cart MRP only - {}", e);
+                request.total_mrp(total_mrp)
+            }
+        };
+        self.pos_checkout(request.build()).await?;
         Ok(())
     }
 }
+
+impl Default for PosCheckoutApi {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -28,3 +28,9 @@ impl PosGetCustomerApi {
         Ok(())
     }
 }
+
+impl Default for PosGetCustomerApi {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -33,3 +33,9 @@ impl PosItemsAllApi {
         Ok(())
     }
 }
+
+impl Default for PosItemsAllApi {
+    fn default() -> Self {
+        Self::new()
+    }
+}
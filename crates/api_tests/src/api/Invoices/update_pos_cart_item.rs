@@ -1,9 +1,9 @@
 use crate::api_context::ApiContext;
-use crate::request_response::Invoice_request_response::pos_cart_update_request::{
-    Items, UpdatePosCartItemRequest, Value as RequestValue,
-};
+use crate::request_response::Invoice_request_response::pos_cart_update_request::UpdatePosCartItemRequest;
+use crate::utils::request_factories::UpdatePosCartItemRequestFactory;
 use crate::ApiClient;
 use anyhow::Result;
+use lib_test_helpers::config::get_config;
 use serde_json::Value;
 
 pub struct UpdatePosCartItemApi {
@@ -22,7 +22,7 @@ impl UpdatePosCartItemApi {
     pub async fn update_pos_cart_item(
         &self,
         update_pos_cart_item_request: UpdatePosCartItemRequest,
-    ) -> Result<()> {
+    ) -> Result<Value> {
         let response: Value = self
             .client
             .post("/api/v1/admin/update-pos-cart-item-wms")
@@ -32,7 +32,7 @@ impl UpdatePosCartItemApi {
         self.ctx
             .store_raw("update_pos_cart_item", "data", response.clone())
             .unwrap();
-        Ok(())
+        Ok(response)
     }
 
     pub async fn update_pos_cart_item_with_factory(&self, node_id: &str) -> Result<()> {
@@ -42,21 +42,17 @@ impl UpdatePosCartItemApi {
         let uuid = user["id"].as_str().unwrap();
         let pos_item: Value = self
             .ctx
-            .get_row_map("pos_items_all", "data", 17009)
+            .get_row_map("pos_items_all", "data", get_config().fixtures.pos_item_id)
             .unwrap();
         let node_item_id = pos_item["uuid"].as_str().unwrap();
 
-        self.update_pos_cart_item(UpdatePosCartItemRequest {
-            customer_id: RequestValue::String(uuid.to_string()),
-            customer_type: RequestValue::String("registered".to_string()),
-            node_id: RequestValue::String(node_id.to_string()),
-            cart_items: vec![Items::New {
-                node_item_id: RequestValue::String(node_item_id.to_string()),
-                price: RequestValue::Usize(0.00),
-                quantity: RequestValue::Usize(1.00),
-                discount_percentage: RequestValue::Usize(0.00),
-            }],
-        })
+        self.update_pos_cart_item(
+            UpdatePosCartItemRequestFactory::new()
+                .customer_id(uuid)
+                .node_id(node_id)
+                .new_item(node_item_id, 0.00, 1.00)
+                .build(),
+        )
         .await?;
         Ok(())
     }
@@ -72,7 +68,7 @@ impl UpdatePosCartItemApi {
         let uuid = user["id"].as_str().unwrap();
         let pos_item: Value = self
             .ctx
-            .get_row_map("pos_items_all", "data", 17009)
+            .get_row_map("pos_items_all", "data", get_config().fixtures.pos_item_id)
             .unwrap();
         let node_item_id = pos_item["uuid"].as_str().unwrap();
 
@@ -90,19 +86,20 @@ impl UpdatePosCartItemApi {
             .as_str()
             .expect("Expected uuid to be a string");
 
-        self.update_pos_cart_item(UpdatePosCartItemRequest {
-            customer_id: RequestValue::String(uuid.to_string()),
-            customer_type: RequestValue::String("registered".to_string()),
-            node_id: RequestValue::String(node_id.to_string()),
-            cart_items: vec![Items::Update {
-                uuid: RequestValue::String(snapshot_id.to_string()),
-                node_item_id: RequestValue::String(node_item_id.to_string()),
-                price: RequestValue::Usize(*price),
-                quantity: RequestValue::Isize(1),
-                discount_percentage: RequestValue::Usize(0.00),
-            }],
-        })
+        self.update_pos_cart_item(
+            UpdatePosCartItemRequestFactory::new()
+                .customer_id(uuid)
+                .node_id(node_id)
+                .update_item(snapshot_id, node_item_id, *price, 1)
+                .build(),
+        )
         .await?;
         Ok(())
     }
 }
+
+impl Default for UpdatePosCartItemApi {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,7 +1,7 @@
 use crate::api_context::ApiContext;
 use crate::ApiClient;
 use anyhow::Result;
-use serde_json::json;
+use serde_json::{json, Value};
 pub struct VoidInvoiceApi {
     client: &'static ApiClient,
     ctx: &'static ApiContext,
@@ -15,18 +15,15 @@ impl VoidInvoiceApi {
         }
     }
 
-    pub async fn invoice_list(&self, uuid: &str) -> Result<()> {
-        let response = self
+    pub async fn invoice_list(&self, uuid: &str) -> Result<Value> {
+        let response: Value = self
             .client
             .get(format!("/api/v1/admin/invoice-listing-wms?uuid={uuid}&page=1&limit=20").as_str())
             .send_json()
-            .await;
-        self.ctx.store_raw(
-            "invoice_list",
-            "data",
-            response.as_ref().ok().cloned().unwrap(),
-        )?;
-        Ok(())
+            .await?;
+        self.ctx
+            .store_raw("invoice_list", "data", response.clone())?;
+        Ok(response)
     }
 
     pub async fn void_invoice(&self, invoice_uuid: &str) -> Result<String> {
@@ -88,3 +85,9 @@ impl VoidInvoiceApi {
         Ok(())
     }
 }
+
+impl Default for VoidInvoiceApi {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,8 +1,8 @@
 use crate::api_context::ApiContext;
 use crate::client::ApiClient;
-use crate::factories;
 use crate::request_response::user_actions::elder_data_edit::ElderDataEditRequest;
 use crate::request_response::user_actions::elder_data_edit::ElderDataEditResponse;
+use crate::utils::request_factories::ElderDataEditRequestFactory;
 
 use anyhow::Result;
 use serde_json::Value;
@@ -67,29 +67,20 @@ impl ElderDataEditApi {
             .as_str()
             .ok_or_else(|| anyhow::anyhow!("'mobile_number' not found or not a string"))?;
 
-        self.update_elder_data(ElderDataEditRequest {
-            currentElderIdentifier: uuid.to_string(),
-            consumeraddress_uuid: consumeraddress_uuid.to_string(),
-            phone_no: mobile_number.to_string(),
-            is_nok_elder_calling: "elder".to_string(),
-            email: factories::TestDataFactory::email(),
-            reasons_for_downloading_app: None,
-            first_name: "Test".to_string(),
-            age: Some(58),
-            languages: None,
-            last_name: factories::TestDataFactory::last_name(),
-            gender: 1,
-            current_medical_conditions: None,
-            pincode: None,
-            address_line_1: None,
-            address_line_2: None,
-            city: None,
-            state: None,
-            locality: None,
-            country: None,
-            new_selected_ep_uuid: None,
-            r#override: None,
-        })
+        self.update_elder_data(
+            ElderDataEditRequestFactory::new()
+                .currentElderIdentifier(uuid)
+                .consumeraddress_uuid(consumeraddress_uuid)
+                .phone_no(mobile_number)
+                .first_name("Test")
+                .build(),
+        )
         .await
     }
 }
+
+impl Default for ElderDataEditApi {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -41,7 +41,7 @@ impl LeadStageUpdateApi {
         let path = "crates/api_tests/docs/lead_uuid.txt";
         let file = File::open(path).unwrap();
         let reader = io::BufReader::new(file);
-        let uuid = reader.lines().filter_map(Result::ok).last().unwrap();
+        let uuid = reader.lines().map_while(Result::ok).last().unwrap();
 
         let stage_uuid_path = "crates/api_tests/docs/lead_stage_uuid.json";
         let file_contents = fs::read_to_string(stage_uuid_path).unwrap();
@@ -53,7 +53,7 @@ impl LeadStageUpdateApi {
         let path_profile_uuid = "crates/api_tests/docs/profile_uuid.txt";
         let file = File::open(path_profile_uuid).unwrap();
         let reader = io::BufReader::new(file);
-        let profile_uuid = reader.lines().filter_map(Result::ok).last().unwrap();
+        let profile_uuid = reader.lines().map_while(Result::ok).last().unwrap();
         self.update_lead_stage(LeadStageUpdateRequest {
             lead_uuid: uuid.to_string(),
             lead_stage_1_uuid: stages.lead_stage_1_uuid.to_string(),
@@ -72,3 +72,9 @@ impl LeadStageUpdateApi {
         .await
     }
 }
+
+impl Default for LeadStageUpdateApi {
+    fn default() -> Self {
+        Self::new()
+    }
+}
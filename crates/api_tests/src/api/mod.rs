@@ -9,6 +9,7 @@ pub mod create_invoice;
 
 pub use Auth::auth_api;
 pub use CreateLead::lead_create;
+pub use CreateLead::lead_search;
 pub use GlobalUser::current_user;
 pub use ProfileActions::elder_data_edit_api;
 pub use ProfileActions::lead_stage_update;
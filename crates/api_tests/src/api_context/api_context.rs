@@ -36,7 +36,7 @@ impl ApiContext {
     }
 
     pub fn global() -> &'static ApiContext {
-        static GLOBAL: LazyLock<ApiContext> = LazyLock::new(|| ApiContext::new());
+        static GLOBAL: LazyLock<ApiContext> = LazyLock::new(ApiContext::new);
         &GLOBAL
     }
 
@@ -1,2 +1,3 @@
+#[allow(clippy::module_inception)]
 pub mod api_context;
 pub use api_context::{ApiContext, ApiContextError};
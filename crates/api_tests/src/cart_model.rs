@@ -0,0 +1,396 @@
+use crate::api::current_user::CurrentUserApi;
+use crate::api::lead_create::LeadCreateRequestApi;
+use crate::api::Invoices::pos_checkout::PosCheckoutApi;
+use crate::api::Invoices::update_pos_cart_item::UpdatePosCartItemApi;
+use crate::api_context::ApiContext;
+use crate::client::is_server_error;
+use crate::pricing::{self, to_paise, CartLine, PricingOracle};
+use crate::property::ModelCheck;
+use crate::reporting::TestCollector;
+use crate::request_response::Invoice_request_response::pos_cart_update_request::{
+    Items, Value as CartValue,
+};
+use crate::request_response::Invoice_request_response::pos_items_response::PosItem;
+use crate::utils::request_factories::{PosCheckoutRequestFactory, UpdatePosCartItemRequestFactory};
+use anyhow::{Context, Result};
+use lib_test_helpers::config::get_config;
+use proptest::collection::vec;
+use proptest::prelude::*;
+use proptest::sample::Index;
+use serde_json::Value;
+use std::cell::Cell;
+
+const ENDPOINT: &str = "/api/v1/admin/update-pos-cart-item-wms";
+const API_NAME: &str = "Update POS Cart Item API";
+/// Items the generator picks from, taken from the front of the POS item list.
+const MAX_CATALOGUE_ITEMS: usize = 5;
+const MAX_SEQUENCE_LENGTH: usize = 10;
+/// Every shrink step creates a customer and replays a sequence, so shrinking is kept short.
+const MAX_SHRINK_ITERS: u32 = 32;
+pub const DEFAULT_CART_SEQUENCES: u32 = 10;
+
+/// One cart operation. Lines and items are picked by index into whatever the cart or
+/// catalogue holds when the command runs, so sequences stay meaningful while shrinking.
+#[derive(Debug, Clone)]
+pub enum Command {
+    Add {
+        item: Index,
+        quantity: u32,
+    },
+    SetQuantity {
+        line: Index,
+        quantity: u32,
+    },
+    /// New price as a percentage of the item's MRP.
+    SetPrice {
+        line: Index,
+        percent_of_mrp: u32,
+    },
+    Discount {
+        line: Index,
+        percent: u32,
+    },
+    Remove {
+        line: Index,
+    },
+    Checkout,
+}
+
+#[derive(Debug, Clone)]
+struct ModelLine {
+    item: PosItem,
+    /// What the test asked for; the price only sticks on editable items.
+    requested: CartLine,
+    /// Cart row UUID once the server has returned one.
+    uuid: Option<String>,
+}
+
+impl ModelLine {
+    fn expected(&self) -> CartLine {
+        let mut line = self.requested.clone();
+        if self.item.is_editable == 0 {
+            line.unit_price = self.item.price;
+        }
+        line
+    }
+
+    fn request_item(&self, quantity: u32) -> Items {
+        let node_item_id = CartValue::String(self.item.uuid.clone());
+        let price = CartValue::Usize(self.requested.unit_price);
+        let discount_percentage = CartValue::Usize(self.requested.discount_percent);
+        match &self.uuid {
+            Some(uuid) => Items::Update {
+                uuid: CartValue::String(uuid.clone()),
+                node_item_id,
+                price,
+                quantity: CartValue::Isize(quantity as i64),
+                discount_percentage,
+            },
+            None => Items::New {
+                node_item_id,
+                price,
+                quantity: CartValue::Usize(quantity as f64),
+                discount_percentage,
+            },
+        }
+    }
+}
+
+/// Reference POS cart: one line per item, price edits ignored on non-editable items,
+/// discounts capped at the item's maximum, totals from the pricing oracle. Checking out
+/// empties the cart and the next add opens a new one.
+#[derive(Debug, Clone, Default)]
+pub struct CartModel {
+    lines: Vec<ModelLine>,
+    cart_uuid: Option<String>,
+    checked_out: Vec<String>,
+}
+
+impl CartModel {
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// Applies a cart edit to the model and returns the items to send, or `None` when the
+    /// command has nothing to act on. Removed lines are sent with quantity 0.
+    fn apply(&mut self, command: &Command, catalogue: &[PosItem]) -> Option<Vec<Items>> {
+        let mut removed = None;
+        match command {
+            Command::Add { item, quantity } => {
+                let item = item.get(catalogue);
+                match self.lines.iter_mut().find(|l| l.item.uuid == item.uuid) {
+                    Some(line) => line.requested.quantity += quantity,
+                    None => self.lines.push(ModelLine {
+                        item: item.clone(),
+                        requested: CartLine::from_item(item, *quantity),
+                        uuid: None,
+                    }),
+                }
+            }
+            Command::SetQuantity { line, quantity } => {
+                self.line_mut(line)?.requested.quantity = *quantity;
+            }
+            Command::SetPrice {
+                line,
+                percent_of_mrp,
+            } => {
+                let line = self.line_mut(line)?;
+                let price = to_paise(line.item.price * *percent_of_mrp as f64 / 100.0);
+                line.requested.unit_price = pricing::to_rupees(price);
+            }
+            Command::Discount { line, percent } => {
+                self.line_mut(line)?.requested.discount_percent = *percent as f64;
+            }
+            Command::Remove { line } => {
+                if self.lines.is_empty() {
+                    return None;
+                }
+                removed = Some(self.lines.remove(line.index(self.lines.len())));
+            }
+            Command::Checkout => return None,
+        }
+
+        let mut items: Vec<Items> = self
+            .lines
+            .iter()
+            .map(|l| l.request_item(l.requested.quantity))
+            .collect();
+        if let Some(line) = removed.filter(|l| l.uuid.is_some()) {
+            items.push(line.request_item(0));
+        }
+        Some(items)
+    }
+
+    fn line_mut(&mut self, line: &Index) -> Option<&mut ModelLine> {
+        if self.lines.is_empty() {
+            return None;
+        }
+        let idx = line.index(self.lines.len());
+        self.lines.get_mut(idx)
+    }
+
+    fn expected_lines(&self) -> Vec<CartLine> {
+        self.lines.iter().map(ModelLine::expected).collect()
+    }
+
+    /// Compares a cart response with the model and adopts the row UUIDs the server assigned.
+    fn reconcile(&mut self, response: &Value) -> Vec<String> {
+        let data = &response["data"];
+        let mut diffs = Vec::new();
+
+        let cart_uuid = data["cart_uuid"].as_str().map(str::to_string);
+        if let Some(uuid) = &cart_uuid {
+            if self.checked_out.contains(uuid) {
+                diffs.push(format!("cart {} was reused after checkout", uuid));
+            }
+        }
+        self.cart_uuid = cart_uuid;
+
+        let rows: Vec<&Value> = data["data"]
+            .as_array()
+            .map(|rows| {
+                rows.iter()
+                    .filter(|row| row["quantity"].as_f64().unwrap_or(1.0) > 0.0)
+                    .collect()
+            })
+            .unwrap_or_default();
+        if rows.len() != self.lines.len() {
+            diffs.push(format!(
+                "expected {} cart line(s), server has {}",
+                self.lines.len(),
+                rows.len()
+            ));
+        }
+
+        for line in &mut self.lines {
+            let expected = line.expected();
+            let Some(row) = rows
+                .iter()
+                .find(|row| row["node_item_id"].as_str() == Some(line.item.uuid.as_str()))
+            else {
+                diffs.push(format!("'{}' missing from the cart", line.item.item_name));
+                continue;
+            };
+            line.uuid = row["uuid"].as_str().map(str::to_string);
+            if let Some(quantity) = row["quantity"].as_f64() {
+                if quantity.round() as u32 != expected.quantity {
+                    diffs.push(format!(
+                        "'{}' quantity: expected {}, got {}",
+                        line.item.item_name, expected.quantity, quantity
+                    ));
+                }
+            }
+            if let Some(price) = row["price"].as_f64() {
+                if to_paise(price) != to_paise(expected.unit_price) {
+                    diffs.push(format!(
+                        "'{}' price: expected {:.2}, got {:.2}",
+                        line.item.item_name, expected.unit_price, price
+                    ));
+                }
+            }
+        }
+
+        if !self.lines.is_empty() {
+            let totals = PricingOracle::from_config().cart(&self.expected_lines());
+            match pricing::totals_mismatches(&totals, data) {
+                Ok(mismatches) => diffs.extend(mismatches),
+                Err(e) => diffs.push(e.to_string()),
+            }
+        }
+        diffs
+    }
+
+    fn checkout_request(&self) -> PosCheckoutRequestFactory {
+        let mut request = PosCheckoutRequestFactory::new()
+            .cart_id(self.cart_uuid.clone().unwrap_or_default())
+            .totals(&PricingOracle::from_config().cart(&self.expected_lines()));
+        for uuid in self.lines.iter().filter_map(|l| l.uuid.as_ref()) {
+            request = request.cart_item(uuid);
+        }
+        request
+    }
+
+    fn checked_out(&mut self) {
+        self.lines.clear();
+        if let Some(uuid) = self.cart_uuid.take() {
+            self.checked_out.push(uuid);
+        }
+    }
+}
+
+pub fn any_command() -> impl Strategy<Value = Command> {
+    prop_oneof![
+        4 => (any::<Index>(), 1..5u32).prop_map(|(item, quantity)| Command::Add { item, quantity }),
+        2 => (any::<Index>(), 1..5u32)
+            .prop_map(|(line, quantity)| Command::SetQuantity { line, quantity }),
+        2 => (any::<Index>(), 50..150u32)
+            .prop_map(|(line, percent_of_mrp)| Command::SetPrice { line, percent_of_mrp }),
+        2 => (any::<Index>(), 0..=100u32).prop_map(|(line, percent)| Command::Discount { line, percent }),
+        1 => any::<Index>().prop_map(|line| Command::Remove { line }),
+        1 => Just(Command::Checkout),
+    ]
+}
+
+pub fn command_sequence() -> impl Strategy<Value = Vec<Command>> {
+    vec(any_command(), 1..=MAX_SEQUENCE_LENGTH)
+}
+
+/// Items with a UUID from the POS item list fetched earlier in the run.
+fn catalogue(ctx: &ApiContext) -> Result<Vec<PosItem>> {
+    let items: Vec<PosItem> =
+        serde_json::from_value(ctx.get_raw("pos_items_all", "data")?["data"].clone())
+            .context("POS item list has no data")?;
+    let items: Vec<PosItem> = items
+        .into_iter()
+        .filter(|item| !item.uuid.is_empty())
+        .take(MAX_CATALOGUE_ITEMS)
+        .collect();
+    if items.is_empty() {
+        anyhow::bail!("POS item list is empty");
+    }
+    Ok(items)
+}
+
+/// A customer with no cart yet: a new lead, resolved to its elder profile id.
+pub(crate) async fn new_customer() -> Result<String> {
+    let lead = LeadCreateRequestApi::new()
+        .create_lead_with_factory()
+        .await
+        .context("Could not create a customer for the cart")?;
+    let user = CurrentUserApi::new()
+        .get_current_user(&lead.data.userId)
+        .await?;
+    user["data"][0]["id"]
+        .as_str()
+        .map(str::to_string)
+        .context("Elder profile has no id")
+}
+
+/// Runs one command sequence against a fresh cart. Returns the command log, or the log up to
+/// the first command after which the server cart differed from the model.
+async fn run_sequence(
+    catalogue: &[PosItem],
+    commands: &[Command],
+    sent: &Cell<u32>,
+) -> Result<std::result::Result<Vec<String>, Vec<String>>> {
+    let customer_id = new_customer().await?;
+    let node_id = &get_config().fixtures.pos_node_id;
+    let cart_api = UpdatePosCartItemApi::new();
+    let checkout_api = PosCheckoutApi::new();
+
+    let mut model = CartModel::default();
+    let mut log = Vec::new();
+    for command in commands {
+        let diffs = if let Command::Checkout = command {
+            let response = checkout_api
+                .pos_checkout(model.checkout_request().pos_node_id(node_id).build())
+                .await;
+            sent.set(sent.get() + 1);
+            match (model.is_empty(), response) {
+                (_, Err(e)) if is_server_error(&e) => vec![format!("server error: {}", e)],
+                (true, Ok(())) => vec!["checkout of an empty cart was accepted".to_string()],
+                (true, Err(_)) => Vec::new(),
+                (false, Ok(())) => {
+                    model.checked_out();
+                    Vec::new()
+                }
+                (false, Err(e)) => vec![format!("checkout rejected: {}", e)],
+            }
+        } else {
+            let Some(items) = model.apply(command, catalogue) else {
+                log.push(format!("{:?} - skipped, cart is empty", command));
+                continue;
+            };
+            let response = cart_api
+                .update_pos_cart_item(
+                    UpdatePosCartItemRequestFactory::new()
+                        .customer_id(customer_id.as_str())
+                        .node_id(node_id)
+                        .cart_items(items)
+                        .build(),
+                )
+                .await;
+            sent.set(sent.get() + 1);
+            match response {
+                Ok(response) => model.reconcile(&response),
+                Err(e) => vec![format!("update rejected: {}", e)],
+            }
+        };
+
+        if diffs.is_empty() {
+            log.push(format!("{:?} - ok", command));
+        } else {
+            log.push(format!("{:?} - {}", command, diffs.join("; ")));
+            return Ok(Err(log));
+        }
+    }
+    Ok(Ok(log))
+}
+
+/// Executes `sequences` random command sequences, each on a new customer's cart, comparing
+/// the server cart with the model after every command. A divergence is shrunk to the
+/// shortest sequence that still shows it.
+pub async fn run_cart_model(collector: &TestCollector, sequences: u32) -> Result<()> {
+    let catalogue = catalogue(ApiContext::global())?;
+
+    let sent = Cell::new(0);
+
+    let result = ModelCheck {
+        model: "Cart model",
+        step_name: format!("{} - Cart model ({} sequences)", API_NAME, sequences),
+        api_name: API_NAME,
+        method: "POST",
+        endpoint: ENDPOINT,
+        disagreement: "Server cart diverged from the model",
+        cases: sequences,
+        max_shrink_iters: MAX_SHRINK_ITERS,
+    }
+    .run(&command_sequence(), |commands| {
+        let (catalogue, sent) = (&catalogue, &sent);
+        async move { run_sequence(catalogue, &commands, sent).await }
+    })
+    .await;
+
+    collector.record(result.with_metric("cart_commands", sent.get() as f64));
+    Ok(())
+}
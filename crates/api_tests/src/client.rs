@@ -30,12 +30,15 @@ impl ApiClient {
         })
     }
 
+    /// A client for the configured `api_base_url` with its own cookies and token.
+    pub fn from_config() -> Result<Self> {
+        let config = lib_test_helpers::config::get_config();
+        Self::new(config.api_base_url.trim_end_matches('/').to_string())
+    }
+
     pub fn global() -> &'static ApiClient {
         HTTP_CLIENT.get_or_init(|| {
-            let config = lib_test_helpers::config::get_config();
-            let base_url = config.api_base_url.trim_end_matches('/').to_string();
-
-            ApiClient::new(base_url).expect("Failed to initialize global API client")
+            ApiClient::from_config().expect("Failed to initialize global API client")
         })
     }
 
@@ -79,6 +82,17 @@ impl ApiClient {
         RequestBuilder::new(self.clone(), self.client.delete(&url))
     }
 
+    pub fn request(&self, method: &str, path: &str) -> Result<RequestBuilder> {
+        match method.to_uppercase().as_str() {
+            "GET" => Ok(self.get(path)),
+            "POST" => Ok(self.post(path)),
+            "PUT" => Ok(self.put(path)),
+            "PATCH" => Ok(self.patch(path)),
+            "DELETE" => Ok(self.delete(path)),
+            other => anyhow::bail!("Unsupported HTTP method: {}", other),
+        }
+    }
+
     fn build_url(&self, path: &str) -> String {
         let path = path.trim_start_matches('/');
         format!("{}/{}", self.base_url, path)
@@ -128,6 +142,11 @@ impl RequestBuilder {
     }
 
     pub async fn send_json<T: DeserializeOwned>(self) -> Result<T> {
+        self.send_json_with_status().await.map(|(_, data)| data)
+    }
+
+    /// `send_json`, also returning the (successful) response status.
+    pub async fn send_json_with_status<T: DeserializeOwned>(self) -> Result<(u16, T)> {
         let response = self.send().await?;
         let status = response.status();
         let url = response.url().clone();
@@ -152,7 +171,7 @@ impl RequestBuilder {
         let data =
             serde_json::from_str::<T>(&body_text).context("Failed to deserialize JSON response")?;
 
-        Ok(data)
+        Ok((status.as_u16(), data))
     }
 
     pub async fn send_text(self) -> Result<String> {
@@ -172,3 +191,17 @@ impl RequestBuilder {
         Ok(text)
     }
 }
+
+/// The response status in an error `send_json`/`send_text` returned for a non-2xx response.
+pub fn error_status(e: &anyhow::Error) -> Option<u16> {
+    e.to_string()
+        .strip_prefix("Request failed with status ")?
+        .get(..3)?
+        .parse()
+        .ok()
+}
+
+/// Whether `e` is the error `send_json`/`send_text` return for a 5xx response.
+pub fn is_server_error(e: &anyhow::Error) -> bool {
+    error_status(e).is_some_and(|status| status >= 500)
+}
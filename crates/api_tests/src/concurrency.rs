@@ -0,0 +1,382 @@
+use crate::api::Invoices::update_pos_cart_item::UpdatePosCartItemApi;
+use crate::api::Invoices::void_invoice::VoidInvoiceApi;
+use crate::api_context::ApiContext;
+use crate::cart_model::new_customer;
+use crate::client::ApiClient;
+use crate::pricing;
+use crate::reporting::{TestCollector, TestResult};
+use crate::request_response::Invoice_request_response::pos_checkout_request::PosCheckoutRequest;
+use crate::utils::request_factories::{PosCheckoutRequestFactory, UpdatePosCartItemRequestFactory};
+use anyhow::{Context, Result};
+use futures::future::join_all;
+use lib_test_helpers::config::get_config;
+use serde_json::{json, Value};
+use std::time::{Duration, Instant};
+
+const CHECKOUT_ENDPOINT: &str = "/api/v1/admin/pos-checkout";
+const VOID_ENDPOINT: &str = "/api/v1/admin/void-receipt";
+const GROUP: &str = "Checkout & Void - Concurrency";
+/// The listing can trail the write that created or voided an invoice, and a duplicate can
+/// land after the first one, so it is read this many times before asserting on it.
+const LISTING_ATTEMPTS: u32 = 5;
+const LISTING_INTERVAL: Duration = Duration::from_secs(1);
+pub const DEFAULT_CONCURRENT_REQUESTS: usize = 5;
+
+/// One request of a burst and what came back; `status` is `None` if it never got a response.
+#[derive(Debug, Clone)]
+pub struct Shot {
+    pub label: String,
+    pub status: Option<u16>,
+    pub body: String,
+    pub duration_ms: u64,
+}
+
+impl Shot {
+    pub fn succeeded(&self) -> bool {
+        matches!(self.status, Some(200..=299))
+    }
+
+    pub fn server_error(&self) -> bool {
+        self.status.is_none_or(|status| status >= 500)
+    }
+
+    fn summary(&self) -> String {
+        format!(
+            "{}: {} in {}ms - {}",
+            self.label,
+            self.status
+                .map_or("no response".to_string(), |s| s.to_string()),
+            self.duration_ms,
+            self.body.chars().take(200).collect::<String>()
+        )
+    }
+}
+
+async fn fire(label: String, method: &str, path: &str, body: Value) -> Shot {
+    let start = Instant::now();
+    let response = match ApiClient::global().request(method, path) {
+        Ok(request) => request.json(&body).send().await,
+        Err(e) => Err(e),
+    };
+    let (status, body) = match response {
+        Ok(response) => {
+            let status = response.status().as_u16();
+            (Some(status), response.text().await.unwrap_or_default())
+        }
+        Err(e) => (None, e.to_string()),
+    };
+    Shot {
+        label,
+        status,
+        body,
+        duration_ms: start.elapsed().as_millis() as u64,
+    }
+}
+
+fn checkout(
+    label: String,
+    request: &PosCheckoutRequest,
+) -> impl std::future::Future<Output = Shot> {
+    let body = serde_json::to_value(request).unwrap_or_default();
+    fire(label, "POST", CHECKOUT_ENDPOINT, body)
+}
+
+fn void(label: String, receipt_uuid: &str) -> impl std::future::Future<Output = Shot> {
+    fire(
+        label,
+        "PUT",
+        VOID_ENDPOINT,
+        json!({ "invoice_uuid": receipt_uuid }),
+    )
+}
+
+/// A new customer with one fixture item in their cart, and the checkout request for it.
+pub(crate) async fn prepared_checkout() -> Result<(String, PosCheckoutRequest)> {
+    let ctx = ApiContext::global();
+    let fixtures = &get_config().fixtures;
+    let customer_id = new_customer().await?;
+    let pos_item: Value = ctx
+        .get_row_map("pos_items_all", "data", fixtures.pos_item_id)
+        .context("Fixture POS item not loaded")?;
+    let node_item_id = pos_item["uuid"]
+        .as_str()
+        .context("Fixture POS item has no uuid")?;
+
+    let cart = UpdatePosCartItemApi::new()
+        .update_pos_cart_item(
+            UpdatePosCartItemRequestFactory::new()
+                .customer_id(customer_id.as_str())
+                .node_id(fixtures.pos_node_id.as_str())
+                .new_item(node_item_id, 0.00, 1.00)
+                .build(),
+        )
+        .await?;
+    let cart_id = cart["data"]["cart_uuid"]
+        .as_str()
+        .context("Cart response has no cart_uuid")?;
+    let cart_item_id = cart["data"]["data"][0]["uuid"]
+        .as_str()
+        .context("Cart response has no items")?;
+
+    let request = PosCheckoutRequestFactory::new()
+        .pos_node_id(fixtures.pos_node_id.as_str())
+        .cart_id(cart_id)
+        .cart_item(cart_item_id)
+        .totals(&pricing::expected_cart(ctx)?)
+        .build();
+    Ok((customer_id, request))
+}
+
+/// The customer's invoices once the listing has settled: every attempt is read and the
+/// longest listing kept, so a late duplicate is not missed.
+async fn invoices(customer_id: &str) -> Result<Vec<Value>> {
+    let api = VoidInvoiceApi::new();
+    let mut invoices: Vec<Value> = Vec::new();
+    for attempt in 0..LISTING_ATTEMPTS {
+        if attempt > 0 {
+            tokio::time::sleep(LISTING_INTERVAL).await;
+        }
+        let listing = api.invoice_list(customer_id).await?;
+        let listed = listing["data"].as_array().cloned().unwrap_or_default();
+        if listed.len() >= invoices.len() {
+            invoices = listed;
+        }
+    }
+    Ok(invoices)
+}
+
+fn receipt_uuid(invoice: &Value) -> Option<&str> {
+    invoice["receipt_uuid"]
+        .as_str()
+        .or_else(|| invoice["uuid"].as_str())
+}
+
+/// `Some(true)` if the listing marks the invoice void, `None` if it carries no void status.
+fn is_voided(invoice: &Value) -> Option<bool> {
+    for key in ["is_void", "is_voided", "voided"] {
+        match &invoice[key] {
+            Value::Bool(b) => return Some(*b),
+            Value::Number(n) => return Some(n.as_i64() != Some(0)),
+            _ => {}
+        }
+    }
+    ["status", "invoice_status", "receipt_status", "void_status"]
+        .iter()
+        .find_map(|key| invoice[*key].as_str())
+        .map(|status| status.to_ascii_lowercase().contains("void"))
+}
+
+/// Invariants every scenario ends on: one invoice for the cart, no negative money fields,
+/// and, when `voided` is known, a void status that agrees with it.
+fn invoice_violations(invoices: &[Value], voided: Option<bool>) -> Vec<String> {
+    let mut violations = Vec::new();
+    if invoices.len() != 1 {
+        violations.push(format!(
+            "expected exactly one invoice, listing has {}",
+            invoices.len()
+        ));
+    }
+
+    for invoice in invoices {
+        let id = receipt_uuid(invoice).unwrap_or("?");
+        if let Some(fields) = invoice.as_object() {
+            for (key, value) in fields {
+                let money = ["amount", "balance", "total", "due", "paid"]
+                    .iter()
+                    .any(|word| key.to_ascii_lowercase().contains(word));
+                let amount = value
+                    .as_f64()
+                    .or_else(|| value.as_str().and_then(|s| s.trim().parse().ok()));
+                if let (true, Some(amount)) = (money, amount) {
+                    if amount < 0.0 {
+                        violations
+                            .push(format!("invoice {}: {} is negative ({})", id, key, amount));
+                    }
+                }
+            }
+        }
+        if let Some(expected) = voided {
+            match is_voided(invoice) {
+                Some(actual) if actual != expected => violations.push(format!(
+                    "invoice {}: void status is {}, expected {}",
+                    id, actual, expected
+                )),
+                Some(_) => {}
+                None => violations.push(format!("invoice {} has no void status", id)),
+            }
+        }
+    }
+    violations
+}
+
+fn server_errors(shots: &[Shot]) -> Vec<String> {
+    shots
+        .iter()
+        .filter(|shot| shot.server_error())
+        .map(|shot| format!("{} got a server error", shot.label))
+        .collect()
+}
+
+/// Result of one scenario: what was fired and which invariants broke.
+struct Outcome {
+    shots: Vec<Shot>,
+    violations: Vec<String>,
+}
+
+/// `n` identical checkouts of the same cart at once.
+async fn double_checkout(n: usize) -> Result<Outcome> {
+    let (customer_id, request) = prepared_checkout().await?;
+    let shots = join_all((1..=n).map(|i| checkout(format!("checkout #{}", i), &request))).await;
+
+    let mut violations = server_errors(&shots);
+    if !shots.iter().any(Shot::succeeded) {
+        violations.push("no checkout succeeded".to_string());
+    }
+    violations.extend(invoice_violations(&invoices(&customer_id).await?, None));
+    Ok(Outcome { shots, violations })
+}
+
+/// `n` voids of the same invoice at once.
+async fn double_void(n: usize) -> Result<Outcome> {
+    let (customer_id, request) = prepared_checkout().await?;
+    let mut shots = vec![checkout("checkout".to_string(), &request).await];
+    let listed = invoices(&customer_id).await?;
+    let receipt = listed
+        .first()
+        .and_then(receipt_uuid)
+        .context("Checked-out invoice not listed")?
+        .to_string();
+
+    shots.extend(join_all((1..=n).map(|i| void(format!("void #{}", i), &receipt))).await);
+
+    let mut violations = server_errors(&shots);
+    let voided = shots[1..].iter().any(Shot::succeeded);
+    if !voided {
+        violations.push("no void succeeded".to_string());
+    }
+    violations.extend(invoice_violations(
+        &invoices(&customer_id).await?,
+        Some(voided),
+    ));
+    Ok(Outcome { shots, violations })
+}
+
+/// A checkout and a void of its cart sent at the same moment, the only id known before the
+/// receipt exists. Whatever the backend decides, the invoice must end up voided exactly
+/// when the void succeeded.
+async fn void_during_checkout() -> Result<Outcome> {
+    let (customer_id, request) = prepared_checkout().await?;
+    let (checkout_shot, void_shot) = futures::join!(
+        checkout("checkout".to_string(), &request),
+        void("void (cart id)".to_string(), &request.cart_id),
+    );
+    let voided = void_shot.succeeded();
+    let shots = vec![checkout_shot, void_shot];
+
+    let mut violations = server_errors(&shots);
+    let listed = invoices(&customer_id).await?;
+    if shots[0].succeeded() {
+        violations.extend(invoice_violations(&listed, Some(voided)));
+    } else if !listed.is_empty() {
+        violations.push(format!(
+            "checkout was rejected but {} invoice(s) were created",
+            listed.len()
+        ));
+    }
+    Ok(Outcome { shots, violations })
+}
+
+fn record(
+    collector: &TestCollector,
+    name: &str,
+    api_name: &str,
+    endpoint: &str,
+    method: &str,
+    duration_ms: u64,
+    outcome: Result<Outcome>,
+) {
+    let result = match outcome {
+        Ok(Outcome { shots, violations }) => {
+            let log = shots
+                .iter()
+                .map(Shot::summary)
+                .collect::<Vec<_>>()
+                .join("\n");
+            let succeeded = shots.iter().filter(|s| s.succeeded()).count();
+            let result = if violations.is_empty() {
+                println!("{} successful", name);
+                TestResult::passed(name, api_name, method, endpoint, 200, duration_ms)
+            } else {
+                println!("{} failed - {}", name, violations.join("; "));
+                TestResult::failed(
+                    name,
+                    api_name,
+                    method,
+                    endpoint,
+                    None,
+                    duration_ms,
+                    violations.join("\n"),
+                )
+            };
+            result
+                .with_response_body(log)
+                .with_metric("requests", shots.len() as f64)
+                .with_metric("succeeded", succeeded as f64)
+        }
+        Err(e) => {
+            println!("{} could not run - {}", name, e);
+            TestResult::failed(
+                name,
+                api_name,
+                method,
+                endpoint,
+                None,
+                duration_ms,
+                format!("Setup failed: {}", e),
+            )
+        }
+    };
+    collector.record(result.with_group(GROUP));
+}
+
+/// Fires simultaneous checkouts and voids, each scenario on a new customer, and checks the
+/// invoice listing afterwards.
+pub async fn run_concurrency_tests(collector: &TestCollector, n: usize) -> Result<()> {
+    let start = Instant::now();
+    let outcome = double_checkout(n).await;
+    record(
+        collector,
+        &format!("Same cart checked out {} times at once", n),
+        "POS Checkout API",
+        CHECKOUT_ENDPOINT,
+        "POST",
+        start.elapsed().as_millis() as u64,
+        outcome,
+    );
+
+    let start = Instant::now();
+    let outcome = double_void(n).await;
+    record(
+        collector,
+        &format!("Same invoice voided {} times at once", n),
+        "Void Invoice API",
+        VOID_ENDPOINT,
+        "PUT",
+        start.elapsed().as_millis() as u64,
+        outcome,
+    );
+
+    let start = Instant::now();
+    let outcome = void_during_checkout().await;
+    record(
+        collector,
+        "Void of the cart sent while its checkout is in flight",
+        "Void Invoice API",
+        VOID_ENDPOINT,
+        "PUT",
+        start.elapsed().as_millis() as u64,
+        outcome,
+    );
+
+    Ok(())
+}
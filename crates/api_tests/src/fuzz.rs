@@ -0,0 +1,375 @@
+use crate::client::ApiClient;
+use crate::property;
+use crate::reporting::{TestCollector, TestResult};
+use crate::request_response::user_actions::lead::LeadCreateRequest;
+use crate::request_response::Invoice_request_response::pos_cart_update_request::{
+    Items, UpdatePosCartItemRequest, Value as CartValue,
+};
+use anyhow::{Context, Result};
+use proptest::collection::vec;
+use proptest::prelude::*;
+use proptest::test_runner::Config;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+const REGRESSIONS_DIR: &str = "crates/api_tests/fuzz_regressions";
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+const MAX_SHRINK_ITERS: u32 = 200;
+pub const DEFAULT_FUZZ_CASES: u32 = 50;
+
+#[derive(Debug, Clone)]
+pub struct FuzzTarget {
+    pub api_name: String,
+    pub method: String,
+    pub endpoint: String,
+    pub slug: String,
+}
+
+impl FuzzTarget {
+    pub fn new(
+        api_name: impl Into<String>,
+        method: impl Into<String>,
+        endpoint: impl Into<String>,
+        slug: impl Into<String>,
+    ) -> Self {
+        Self {
+            api_name: api_name.into(),
+            method: method.into(),
+            endpoint: endpoint.into(),
+            slug: slug.into(),
+        }
+    }
+
+    fn group(&self) -> String {
+        format!("{} - Fuzzing", self.api_name)
+    }
+
+    fn regressions_path(&self) -> PathBuf {
+        PathBuf::from(REGRESSIONS_DIR).join(format!("{}.json", self.slug))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Regression {
+    pub reason: String,
+    pub seed: u64,
+    pub recorded_at: String,
+    pub payload: Value,
+}
+
+enum Outcome {
+    Status(u16, String),
+    Timeout,
+}
+
+impl Outcome {
+    fn failure(&self) -> Option<String> {
+        match self {
+            Outcome::Status(status, body) if *status >= 500 => Some(format!(
+                "Server error {}: {}",
+                status,
+                body.chars().take(200).collect::<String>()
+            )),
+            Outcome::Status(..) => None,
+            Outcome::Timeout => Some(format!("No response within {}s", REQUEST_TIMEOUT.as_secs())),
+        }
+    }
+}
+
+pub fn cart_value() -> impl Strategy<Value = CartValue> {
+    let leaf = prop_oneof![
+        any::<String>().prop_map(CartValue::String),
+        "[0-9a-f-]{0,40}".prop_map(CartValue::String),
+        any::<i64>().prop_map(CartValue::Isize),
+        (-1e12f64..1e12f64).prop_map(CartValue::Usize),
+    ];
+    leaf.prop_recursive(2, 8, 4, |inner| vec(inner, 0..4).prop_map(CartValue::Array))
+}
+
+pub fn cart_item() -> impl Strategy<Value = Items> {
+    prop_oneof![
+        (cart_value(), cart_value(), cart_value(), cart_value()).prop_map(
+            |(node_item_id, price, quantity, discount_percentage)| Items::New {
+                node_item_id,
+                price,
+                quantity,
+                discount_percentage,
+            }
+        ),
+        (
+            cart_value(),
+            cart_value(),
+            cart_value(),
+            cart_value(),
+            cart_value()
+        )
+            .prop_map(
+                |(uuid, node_item_id, price, quantity, discount_percentage)| Items::Update {
+                    uuid,
+                    node_item_id,
+                    price,
+                    quantity,
+                    discount_percentage,
+                }
+            ),
+    ]
+}
+
+pub fn update_pos_cart_item_request() -> impl Strategy<Value = UpdatePosCartItemRequest> {
+    (
+        cart_value(),
+        cart_value(),
+        cart_value(),
+        vec(cart_item(), 0..4),
+    )
+        .prop_map(|(customer_id, customer_type, node_id, cart_items)| {
+            UpdatePosCartItemRequest {
+                customer_id,
+                customer_type,
+                node_id,
+                cart_items,
+            }
+        })
+}
+
+fn text() -> impl Strategy<Value = String> {
+    prop_oneof![any::<String>(), "[ -~]{0,64}", "[0-9+]{0,15}"]
+}
+
+pub fn lead_create_request() -> impl Strategy<Value = LeadCreateRequest> {
+    (
+        text(),
+        text(),
+        text(),
+        text(),
+        text(),
+        text(),
+        text(),
+        text(),
+    )
+        .prop_map(
+            |(
+                country_code,
+                lead_name,
+                lead_phone,
+                lead_email,
+                lead_source_category,
+                lead_source,
+                campaign_name,
+                lead_owner_uuid,
+            )| LeadCreateRequest {
+                country_code,
+                lead_name,
+                lead_phone,
+                lead_email,
+                lead_source_category,
+                lead_source,
+                campaign_name,
+                lead_owner_uuid,
+            },
+        )
+}
+
+async fn probe(target: &FuzzTarget, body: &Value) -> Result<Outcome> {
+    let request = ApiClient::global()
+        .request(&target.method, &target.endpoint)?
+        .json(body);
+
+    match tokio::time::timeout(REQUEST_TIMEOUT, request.send()).await {
+        Ok(response) => {
+            let response = response?;
+            let status = response.status().as_u16();
+            let body = response.text().await.unwrap_or_default();
+            Ok(Outcome::Status(status, body))
+        }
+        Err(_) => Ok(Outcome::Timeout),
+    }
+}
+
+pub fn load_regressions(target: &FuzzTarget) -> Result<Vec<Regression>> {
+    let path = target.regressions_path();
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content =
+        fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+    serde_json::from_str(&content)
+        .with_context(|| format!("Invalid regression file {}", path.display()))
+}
+
+fn save_regression(target: &FuzzTarget, regression: Regression) -> Result<()> {
+    let mut regressions = load_regressions(target)?;
+    if regressions.iter().any(|r| r.payload == regression.payload) {
+        return Ok(());
+    }
+    regressions.push(regression);
+
+    let path = target.regressions_path();
+    fs::create_dir_all(REGRESSIONS_DIR)?;
+    fs::write(&path, serde_json::to_string_pretty(&regressions)?)
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    println!("Saved fuzz regression to {}", path.display());
+    Ok(())
+}
+
+async fn replay_regressions(collector: &TestCollector, target: &FuzzTarget) -> Result<()> {
+    for (idx, regression) in load_regressions(target)?.iter().enumerate() {
+        let step_name = format!("{} - Fuzz regression #{}", target.api_name, idx + 1);
+        let request_body = serde_json::to_string_pretty(&regression.payload)?;
+        let start = Instant::now();
+        let outcome = probe(target, &regression.payload).await;
+        let duration_ms = start.elapsed().as_millis() as u64;
+
+        let result = match outcome {
+            Ok(outcome) => match (outcome.failure(), outcome) {
+                (None, Outcome::Status(status, _)) => TestResult::passed(
+                    &step_name,
+                    &target.api_name,
+                    &target.method,
+                    &target.endpoint,
+                    status,
+                    duration_ms,
+                ),
+                (failure, outcome) => {
+                    let status = match outcome {
+                        Outcome::Status(status, _) => Some(status),
+                        Outcome::Timeout => None,
+                    };
+                    TestResult::failed(
+                        &step_name,
+                        &target.api_name,
+                        &target.method,
+                        &target.endpoint,
+                        status,
+                        duration_ms,
+                        format!(
+                            "Still failing: {} (first seen: {})",
+                            failure.unwrap_or_default(),
+                            regression.reason
+                        ),
+                    )
+                    .with_request_body(&request_body)
+                }
+            },
+            Err(e) => TestResult::failed(
+                &step_name,
+                &target.api_name,
+                &target.method,
+                &target.endpoint,
+                None,
+                duration_ms,
+                e.to_string(),
+            )
+            .with_request_body(&request_body),
+        };
+
+        collector.record(result.with_group(target.group()));
+    }
+
+    Ok(())
+}
+
+pub async fn fuzz_endpoint<S>(
+    collector: &TestCollector,
+    target: &FuzzTarget,
+    strategy: S,
+    cases: u32,
+) -> Result<()>
+where
+    S: Strategy,
+    S::Value: Serialize,
+{
+    replay_regressions(collector, target).await?;
+
+    let seed = lib_test_helpers::seed::current_seed();
+    let config = Config {
+        cases,
+        failure_persistence: None,
+        max_shrink_iters: MAX_SHRINK_ITERS,
+        ..Config::default()
+    };
+    let statuses: RefCell<BTreeMap<u16, u32>> = RefCell::new(BTreeMap::new());
+
+    let step_name = format!("{} - Fuzz ({} cases)", target.api_name, cases);
+    let start = Instant::now();
+    let run = property::check(&strategy, config, seed, |request| {
+        let statuses = &statuses;
+        async move {
+            let body = serde_json::to_value(&request).context("Unserializable payload")?;
+            let outcome = probe(target, &body).await?;
+            if let Outcome::Status(status, _) = outcome {
+                *statuses.borrow_mut().entry(status).or_default() += 1;
+            }
+            Ok(outcome.failure())
+        }
+    })
+    .await;
+    let duration_ms = start.elapsed().as_millis() as u64;
+
+    let statuses = statuses.into_inner();
+    let most_seen = statuses
+        .iter()
+        .max_by_key(|(_, count)| **count)
+        .map(|(status, _)| *status);
+
+    let mut result = match (run.stop_reason(), run.failure) {
+        (_, Some(failure)) => {
+            let payload = serde_json::to_value(&failure.value)?;
+            println!("{} failed - {}", step_name, failure.reason);
+            save_regression(
+                target,
+                Regression {
+                    reason: failure.reason.clone(),
+                    seed,
+                    recorded_at: chrono::Local::now().to_rfc3339(),
+                    payload: payload.clone(),
+                },
+            )?;
+            TestResult::failed(
+                &step_name,
+                &target.api_name,
+                &target.method,
+                &target.endpoint,
+                None,
+                duration_ms,
+                format!("Minimal failing payload: {}", failure.reason),
+            )
+            .with_request_body(serde_json::to_string_pretty(&payload)?)
+        }
+        (Some(stopped), None) => {
+            println!("{} failed - fuzzing {}", step_name, stopped);
+            TestResult::failed(
+                &step_name,
+                &target.api_name,
+                &target.method,
+                &target.endpoint,
+                most_seen,
+                duration_ms,
+                format!("Fuzzing {}", stopped),
+            )
+        }
+        (None, None) => {
+            println!("{} successful", step_name);
+            TestResult::passed(
+                &step_name,
+                &target.api_name,
+                &target.method,
+                &target.endpoint,
+                most_seen.unwrap_or_default(),
+                duration_ms,
+            )
+        }
+    };
+    result = result.with_metric("cases_run", run.cases_run as f64);
+    for (status, count) in &statuses {
+        result = result.with_metric(format!("status_{}", status), *count as f64);
+    }
+
+    collector.record(result.with_group(target.group()));
+    Ok(())
+}
@@ -1,7 +1,15 @@
 pub mod api;
 pub mod api_context;
+pub mod cart_model;
 pub mod client;
+pub mod concurrency;
+pub mod fuzz;
+pub mod lead_stages;
 pub mod models;
+pub mod negative;
+pub mod parameterized;
+pub mod pricing;
+pub mod property;
 pub mod reporting;
 pub mod request_response;
 pub mod utils;
@@ -10,6 +18,7 @@ pub use api::create_invoice::CreateInvoiceApi;
 pub use api::current_user::CurrentUserApi;
 pub use api::elder_data_edit_api::ElderDataEditApi;
 pub use api::lead_create::LeadCreateRequestApi;
+pub use api::lead_search::LeadSearchApi;
 pub use api::lead_stage_update::LeadStageUpdateApi;
 pub use api::Invoices::pos_checkout::PosCheckoutApi;
 pub use api::Invoices::pos_items_all::PosItemsAllApi;
@@ -18,6 +27,8 @@ pub use api::Invoices::void_invoice::VoidInvoiceApi;
 pub use client::ApiClient;
 use futures::FutureExt;
 pub use models::*;
+use api_context::ApiContext;
+use parameterized::ParameterizedTest;
 use std::panic::AssertUnwindSafe;
 use std::{
     fs::OpenOptions,
@@ -26,30 +37,29 @@ use std::{
 pub use utils::*;
 
 use anyhow::Result;
-use reporting::{
-    ConsoleReporter, HtmlReporter, JUnitReporter, JsonReporter, Reporter, TestCollector,
-};
+use lib_test_helpers::seed;
+use reporting::{ConsoleReporter, TestCollector, TestReport};
 use std::path::Path;
-use std::time::Instant;
-
-fn generate_reports() -> Result<()> {
-    let collector = TestCollector::global();
-    let report = collector.generate_report();
+use std::time::{Duration, Instant};
 
-    ConsoleReporter::new().print(&report);
-
-    let reports_dir = Path::new("crates/api_tests/reports");
-    std::fs::create_dir_all(reports_dir)?;
-    let html_path = reports_dir.join("api_report.html");
-    HtmlReporter::new().save(&report, &html_path)?;
-    println!("HTML Report: {}", html_path.display());
+#[derive(Debug, Clone, Default)]
+pub struct ApiTestOptions {
+    pub data_driven: bool,
+    pub row_pacing: Option<Duration>,
+    pub negative: bool,
+    pub fuzz: bool,
+    pub fuzz_cases: Option<u32>,
+    pub lead_stages: bool,
+    pub lead_stage_walks: Option<u32>,
+    pub cart_model: bool,
+    pub cart_sequences: Option<u32>,
+    pub concurrency: bool,
+    pub concurrent_requests: Option<usize>,
+}
 
-    let json_path = reports_dir.join("api_report.json");
-    JsonReporter::new().save(&report, &json_path)?;
-    println!("JSON Report: {}", json_path.display());
-    let junit_path = reports_dir.join("api_report.xml");
-    JUnitReporter::new().save(&report, &junit_path)?;
-    println!("JUnit Report: {}", junit_path.display());
+fn generate_reports(report: &TestReport) -> Result<()> {
+    ConsoleReporter::new().print(report);
+    reporting::save_all(report, Path::new("crates/api_tests/reports"), "api_report")?;
 
     println!("All reports generated successfully");
 
@@ -64,6 +74,30 @@ fn generate_reports() -> Result<()> {
 }
 
 pub async fn run_all_tests() -> Result<()> {
+    run_tests(ApiTestOptions::default()).await
+}
+
+pub async fn run_tests(options: ApiTestOptions) -> Result<()> {
+    let (report, outcome) = execute(&options).await;
+
+    println!("Generating Reports...");
+    if let Err(e) = generate_reports(&report) {
+        eprintln!("Failed to generate reports: {}", e);
+    }
+
+    outcome
+}
+
+/// Runs the API steps and returns the report without writing it, for combined runs.
+pub async fn run_suite(options: &ApiTestOptions) -> TestReport {
+    let (report, outcome) = execute(options).await;
+    if let Err(e) = outcome {
+        eprintln!("API suite stopped early: {}", e);
+    }
+    report
+}
+
+async fn execute(options: &ApiTestOptions) -> (TestReport, Result<()>) {
     println!("API Testing Started");
 
     let collector = TestCollector::global();
@@ -71,14 +105,11 @@ pub async fn run_all_tests() -> Result<()> {
     collector.start();
     collector.set_suite_name("Emoha API Test Suite");
 
-    let test_result = AssertUnwindSafe(run_test_steps()).catch_unwind().await;
-
-    println!("Generating Reports...");
-    if let Err(e) = generate_reports() {
-        eprintln!("Failed to generate reports: {}", e);
-    }
+    let test_result = AssertUnwindSafe(seed::scoped("api", run_test_steps(options)))
+        .catch_unwind()
+        .await;
 
-    match test_result {
+    let outcome = match test_result {
         Ok(result) => result,
         Err(panic_info) => {
             let msg = if let Some(s) = panic_info.downcast_ref::<&str>() {
@@ -90,11 +121,15 @@ pub async fn run_all_tests() -> Result<()> {
             };
             Err(anyhow::anyhow!("Test execution panicked: {}", msg))
         }
-    }
+    };
+
+    (collector.generate_report(), outcome)
 }
 
-async fn run_test_steps() -> Result<()> {
+async fn run_test_steps(options: &ApiTestOptions) -> Result<()> {
     let collector = TestCollector::global();
+    let fixtures = &lib_test_helpers::config::get_config().fixtures;
+    fixtures.require_pos()?;
 
     let auth = AuthApi::new();
     let lead_create = LeadCreateRequestApi::new();
@@ -105,6 +140,7 @@ async fn run_test_steps() -> Result<()> {
     let update_pos_cart_item = UpdatePosCartItemApi::new();
     let pos_checkout = PosCheckoutApi::new();
     let void_invoice = VoidInvoiceApi::new();
+    let create_invoice = CreateInvoiceApi::new();
 
     let mut should_continue = true;
 
@@ -316,7 +352,7 @@ async fn run_test_steps() -> Result<()> {
     if should_continue {
         let start = Instant::now();
         match get_pos_items_all
-            .get_pos_items_all("ea5639b8-0fe6-4676-b455-65e251cbc492")
+            .get_pos_items_all(&fixtures.pos_node_id)
             .await
         {
             Ok(()) => {
@@ -348,7 +384,7 @@ async fn run_test_steps() -> Result<()> {
     {
         let start = Instant::now();
         match update_pos_cart_item
-            .update_pos_cart_item_with_factory("ea5639b8-0fe6-4676-b455-65e251cbc492")
+            .update_pos_cart_item_with_factory(&fixtures.pos_node_id)
             .await
         {
             Ok(()) => {
@@ -381,7 +417,7 @@ async fn run_test_steps() -> Result<()> {
         let start = Instant::now();
         match update_pos_cart_item
             .update_pos_cart_item_with_factory_and_price(
-                "ea5639b8-0fe6-4676-b455-65e251cbc492",
+                &fixtures.pos_node_id,
                 &100.0,
             )
             .await
@@ -412,10 +448,42 @@ async fn run_test_steps() -> Result<()> {
         }
     }
 
+    {
+        let start = Instant::now();
+        match pricing::verify_cart(ApiContext::global()) {
+            Ok(totals) => {
+                collector.record_passed(
+                    "Step 9a - Verify Cart Totals",
+                    "Update POS Cart Item API",
+                    "PUT",
+                    "/api/v1/admin/update-pos-cart-item-wms",
+                    200,
+                    start.elapsed().as_millis() as u64,
+                );
+                println!(
+                    "Cart totals match the pricing oracle - bill total {:.2}",
+                    pricing::to_rupees(totals.bill_total)
+                );
+            }
+            Err(e) => {
+                collector.record_failed(
+                    "Step 9a - Verify Cart Totals",
+                    "Update POS Cart Item API",
+                    "PUT",
+                    "/api/v1/admin/update-pos-cart-item-wms",
+                    None,
+                    start.elapsed().as_millis() as u64,
+                    e.to_string(),
+                );
+                println!("Verify Cart Totals failed - {}", e);
+            }
+        }
+    }
+
     {
         let start = Instant::now();
         match pos_checkout
-            .pos_checkout_with_factory("ea5639b8-0fe6-4676-b455-65e251cbc492")
+            .pos_checkout_with_factory(&fixtures.pos_node_id)
             .await
         {
             Ok(()) => {
@@ -429,6 +497,36 @@ async fn run_test_steps() -> Result<()> {
                 );
                 println!("POS Checkout successful");
 
+                let verify_start = Instant::now();
+                match pricing::verify_checkout(ApiContext::global()) {
+                    Ok(totals) => {
+                        collector.record_passed(
+                            "Step 10a - Verify Checkout Totals",
+                            "POS Checkout API",
+                            "POST",
+                            "/api/v1/admin/pos-checkout",
+                            200,
+                            verify_start.elapsed().as_millis() as u64,
+                        );
+                        println!(
+                            "Checkout totals match the pricing oracle - bill total {:.2}",
+                            pricing::to_rupees(totals.bill_total)
+                        );
+                    }
+                    Err(e) => {
+                        collector.record_failed(
+                            "Step 10a - Verify Checkout Totals",
+                            "POS Checkout API",
+                            "POST",
+                            "/api/v1/admin/pos-checkout",
+                            None,
+                            verify_start.elapsed().as_millis() as u64,
+                            e.to_string(),
+                        );
+                        println!("Verify Checkout Totals failed - {}", e);
+                    }
+                }
+
                 let void_start = Instant::now();
                 match void_invoice.void_invoice_with_factory().await {
                     Ok(()) => {
@@ -471,5 +569,122 @@ async fn run_test_steps() -> Result<()> {
         }
     }
 
+    if should_continue && options.data_driven {
+        ParameterizedTest::new(
+            "Step 12 - Create Invoice",
+            "Create Invoice API",
+            "POST",
+            "/api/v1/admin/pos-checkout",
+            "crates/api_tests/data/create_invoice_carts.csv",
+        )
+        .pacing(options.row_pacing.unwrap_or(Duration::from_secs(12)))
+        .run(collector, |row| {
+            let create_invoice = &create_invoice;
+            async move {
+                create_invoice
+                    .create_invoice_with_factory(
+                        row.require("cart_id")?.to_string(),
+                        row.require("cart_item_id")?.to_string(),
+                    )
+                    .await
+                    .map(|(status, _)| status)
+            }
+        })
+        .await?;
+    }
+
+    if should_continue && options.negative {
+        negative::run_negative_cases(
+            collector,
+            "Create Lead API",
+            "POST",
+            "/api/v1/admin/add-temp-lead",
+            &LeadCreateRequestFactory::new().build(),
+            &[],
+            false,
+        )
+        .await?;
+
+        // A mutation the backend wrongly accepts checks the cart out, so these run against a
+        // throwaway customer's cart rather than the one the main flow bills, and the cases
+        // after it are failed rather than sent.
+        match concurrency::prepared_checkout().await {
+            Ok((_, checkout)) => {
+                negative::run_negative_cases(
+                    collector,
+                    "POS Checkout API",
+                    "POST",
+                    "/api/v1/admin/pos-checkout",
+                    &checkout,
+                    &["posReceipt_id", "lead_uuid", "cart_items[0].description"],
+                    true,
+                )
+                .await?;
+            }
+            Err(e) => collector.record_failed(
+                "POS Checkout API - Negative cases",
+                "POS Checkout API",
+                "POST",
+                "/api/v1/admin/pos-checkout",
+                None,
+                0,
+                format!("Could not prepare a throwaway cart: {}", e),
+            ),
+        }
+    }
+
+    if should_continue && options.fuzz {
+        let cases = options.fuzz_cases.unwrap_or(fuzz::DEFAULT_FUZZ_CASES);
+        fuzz::fuzz_endpoint(
+            collector,
+            &fuzz::FuzzTarget::new(
+                "Update POS Cart Item API",
+                "POST",
+                "/api/v1/admin/update-pos-cart-item-wms",
+                "update_pos_cart_item",
+            ),
+            fuzz::update_pos_cart_item_request(),
+            cases,
+        )
+        .await?;
+
+        fuzz::fuzz_endpoint(
+            collector,
+            &fuzz::FuzzTarget::new(
+                "Create Lead API",
+                "POST",
+                "/api/v1/admin/add-temp-lead",
+                "create_lead",
+            ),
+            fuzz::lead_create_request(),
+            cases,
+        )
+        .await?;
+    }
+
+    if should_continue && options.lead_stages {
+        let walks = options
+            .lead_stage_walks
+            .unwrap_or(lead_stages::DEFAULT_LEAD_STAGE_WALKS);
+        seed::scoped("api/lead-stages", lead_stages::run_lead_stage_model(collector, walks))
+            .await?;
+    }
+
+    if should_continue && options.cart_model {
+        let sequences = options
+            .cart_sequences
+            .unwrap_or(cart_model::DEFAULT_CART_SEQUENCES);
+        seed::scoped("api/cart-model", cart_model::run_cart_model(collector, sequences))
+            .await?;
+    }
+
+    if should_continue && options.concurrency {
+        let n = options
+            .concurrent_requests
+            .unwrap_or(concurrency::DEFAULT_CONCURRENT_REQUESTS);
+        seed::scoped("api/concurrency", concurrency::run_concurrency_tests(collector, n))
+            .await?;
+    }
+
     Ok(())
 }
@@ -0,0 +1,310 @@
+use crate::client::ApiClient;
+use crate::reporting::{TestCollector, TestResult};
+use anyhow::{bail, Result};
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::time::Instant;
+
+const OVERLONG_LENGTH: usize = 5000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mutation {
+    MissingField,
+    WrongType,
+    EmptyString,
+    OverlongString,
+    NegativeNumber,
+    HugeNumber,
+    InvalidUuid,
+}
+
+impl Mutation {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Mutation::MissingField => "missing field",
+            Mutation::WrongType => "wrong type",
+            Mutation::EmptyString => "empty string",
+            Mutation::OverlongString => "overlong string",
+            Mutation::NegativeNumber => "negative number",
+            Mutation::HugeNumber => "huge number",
+            Mutation::InvalidUuid => "invalid uuid",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Segment {
+    Key(String),
+    Index(usize),
+}
+
+#[derive(Debug, Clone)]
+pub struct NegativeCase {
+    pub mutation: Mutation,
+    pub field: String,
+    pub body: Value,
+}
+
+impl NegativeCase {
+    pub fn name(&self) -> String {
+        format!("{} '{}'", self.mutation.as_str(), self.field)
+    }
+}
+
+/// `optional` lists field paths (as in `NegativeCase::field`) the API accepts without; they
+/// and null fields are never dropped, since omitting them is valid.
+pub fn generate_negative_cases<T: Serialize>(
+    request: &T,
+    optional: &[&str],
+) -> Result<Vec<NegativeCase>> {
+    let base = serde_json::to_value(request)?;
+    if !base.is_object() {
+        bail!("Negative cases can only be generated for JSON object requests");
+    }
+
+    let mut fields = Vec::new();
+    collect_fields(&base, &mut Vec::new(), &mut fields);
+
+    let mut cases = Vec::new();
+    for (path, value) in fields {
+        let field = path_to_string(&path);
+        let omittable = value.is_null() || optional.contains(&field.as_str());
+        for (mutation, replacement) in mutations_for(&field, value, omittable) {
+            let mut body = base.clone();
+            apply(&mut body, &path, replacement);
+            cases.push(NegativeCase {
+                mutation,
+                field: field.clone(),
+                body,
+            });
+        }
+    }
+
+    Ok(cases)
+}
+
+fn collect_fields<'a>(
+    value: &'a Value,
+    path: &mut Vec<Segment>,
+    fields: &mut Vec<(Vec<Segment>, &'a Value)>,
+) {
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                path.push(Segment::Key(key.clone()));
+                fields.push((path.clone(), child));
+                collect_fields(child, path, fields);
+                path.pop();
+            }
+        }
+        Value::Array(items) => {
+            if let Some(first) = items.first() {
+                path.push(Segment::Index(0));
+                collect_fields(first, path, fields);
+                path.pop();
+            }
+        }
+        _ => {}
+    }
+}
+
+fn mutations_for(field: &str, value: &Value, omittable: bool) -> Vec<(Mutation, Option<Value>)> {
+    let mut mutations = Vec::new();
+    if !omittable {
+        mutations.push((Mutation::MissingField, None));
+    }
+
+    match value {
+        Value::String(s) => {
+            mutations.push((Mutation::WrongType, Some(json!(12345))));
+            if !s.is_empty() {
+                mutations.push((Mutation::EmptyString, Some(json!(""))));
+            }
+            mutations.push((
+                Mutation::OverlongString,
+                Some(Value::String("A".repeat(OVERLONG_LENGTH))),
+            ));
+            if looks_like_uuid(s) || is_uuid_field(field) {
+                mutations.push((Mutation::InvalidUuid, Some(json!("not-a-valid-uuid"))));
+                mutations.push((
+                    Mutation::InvalidUuid,
+                    Some(json!("00000000-0000-0000-0000-00000000000g")),
+                ));
+            }
+        }
+        Value::Number(n) => {
+            mutations.push((Mutation::WrongType, Some(json!("not-a-number"))));
+            let negative = n.as_f64().map(|v| -v.abs()).filter(|v| *v != 0.0);
+            mutations.push((
+                Mutation::NegativeNumber,
+                Some(negative.map(|v| json!(v)).unwrap_or(json!(-1))),
+            ));
+            mutations.push((Mutation::HugeNumber, Some(json!(1e15))));
+        }
+        Value::Bool(_) => mutations.push((Mutation::WrongType, Some(json!("yes")))),
+        Value::Array(_) => mutations.push((Mutation::WrongType, Some(json!("not-an-array")))),
+        Value::Object(_) => mutations.push((Mutation::WrongType, Some(json!("not-an-object")))),
+        Value::Null => {
+            if is_uuid_field(field) {
+                mutations.push((Mutation::InvalidUuid, Some(json!("not-a-valid-uuid"))));
+            }
+        }
+    }
+
+    mutations
+}
+
+fn apply(root: &mut Value, path: &[Segment], replacement: Option<Value>) {
+    let Some((last, parents)) = path.split_last() else {
+        return;
+    };
+
+    let mut current = root;
+    for segment in parents {
+        current = match segment {
+            Segment::Key(key) => &mut current[key.as_str()],
+            Segment::Index(index) => &mut current[*index],
+        };
+    }
+
+    match (last, replacement) {
+        (Segment::Key(key), None) => {
+            if let Some(map) = current.as_object_mut() {
+                map.remove(key);
+            }
+        }
+        (Segment::Key(key), Some(value)) => current[key.as_str()] = value,
+        (Segment::Index(index), None) => {
+            if let Some(items) = current.as_array_mut() {
+                items.remove(*index);
+            }
+        }
+        (Segment::Index(index), Some(value)) => current[*index] = value,
+    }
+}
+
+fn path_to_string(path: &[Segment]) -> String {
+    let mut out = String::new();
+    for segment in path {
+        match segment {
+            Segment::Key(key) => {
+                if !out.is_empty() {
+                    out.push('.');
+                }
+                out.push_str(key);
+            }
+            Segment::Index(index) => out.push_str(&format!("[{}]", index)),
+        }
+    }
+    out
+}
+
+fn looks_like_uuid(s: &str) -> bool {
+    s.len() == 36
+        && s.char_indices().all(|(i, c)| match i {
+            8 | 13 | 18 | 23 => c == '-',
+            _ => c.is_ascii_hexdigit(),
+        })
+}
+
+fn is_uuid_field(field: &str) -> bool {
+    let name = field.rsplit('.').next().unwrap_or(field).to_lowercase();
+    name.ends_with("uuid") || name.ends_with("_id")
+}
+
+/// `single_use` marks requests whose target an accepted call uses up, e.g. a checkout and
+/// its cart. Once such a mutation is wrongly accepted, every later case would be rejected
+/// for that reason alone, so they are recorded as failed instead of sent.
+pub async fn run_negative_cases<T: Serialize>(
+    collector: &TestCollector,
+    api_name: &str,
+    method: &str,
+    endpoint: &str,
+    request: &T,
+    optional: &[&str],
+    single_use: bool,
+) -> Result<()> {
+    let client = ApiClient::global();
+    let group = format!("{} - Negative Cases", api_name);
+    let mut used_up_by: Option<String> = None;
+
+    for case in generate_negative_cases(request, optional)? {
+        let step_name = format!("{} - {}", api_name, case.name());
+        let request_body = serde_json::to_string_pretty(&case.body)?;
+
+        if let Some(accepted) = &used_up_by {
+            collector.record(
+                TestResult::failed(
+                    &step_name,
+                    api_name,
+                    method,
+                    endpoint,
+                    None,
+                    0,
+                    format!(
+                        "Not run: {} was accepted and used up the request, so this case \
+                         could not fail on its own",
+                        accepted
+                    ),
+                )
+                .with_request_body(&request_body)
+                .with_group(&group),
+            );
+            continue;
+        }
+        let start = Instant::now();
+
+        let response = client
+            .request(method, endpoint)?
+            .json(&case.body)
+            .send()
+            .await;
+        let duration_ms = start.elapsed().as_millis() as u64;
+
+        let result = match response {
+            Ok(response) => {
+                let status = response.status();
+                let body = response.text().await.unwrap_or_default();
+                if status.is_client_error() {
+                    TestResult::passed(
+                        &step_name,
+                        api_name,
+                        method,
+                        endpoint,
+                        status.as_u16(),
+                        duration_ms,
+                    )
+                } else {
+                    if single_use && status.is_success() {
+                        used_up_by = Some(case.name());
+                    }
+                    TestResult::failed(
+                        &step_name,
+                        api_name,
+                        method,
+                        endpoint,
+                        Some(status.as_u16()),
+                        duration_ms,
+                        format!("Expected 4xx for {}, got {}", case.name(), status),
+                    )
+                    .with_request_body(&request_body)
+                    .with_response_body(body)
+                }
+            }
+            Err(e) => TestResult::failed(
+                &step_name,
+                api_name,
+                method,
+                endpoint,
+                None,
+                duration_ms,
+                e.to_string(),
+            )
+            .with_request_body(&request_body),
+        };
+
+        collector.record(result.with_group(&group));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use crate::client::error_status;
+use crate::reporting::{TestResult, TestCollector};
+use anyhow::Result;
+use lib_test_helpers::data_table::{load_table, DataRow};
+use std::future::Future;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+pub struct ParameterizedTest {
+    name: String,
+    api_name: String,
+    method: String,
+    endpoint: String,
+    data_path: PathBuf,
+    pacing: Duration,
+}
+
+impl ParameterizedTest {
+    pub fn new(
+        name: impl Into<String>,
+        api_name: impl Into<String>,
+        method: impl Into<String>,
+        endpoint: impl Into<String>,
+        data_path: impl Into<PathBuf>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            api_name: api_name.into(),
+            method: method.into(),
+            endpoint: endpoint.into(),
+            data_path: data_path.into(),
+            pacing: Duration::ZERO,
+        }
+    }
+
+    pub fn pacing(mut self, pacing: Duration) -> Self {
+        self.pacing = pacing;
+        self
+    }
+
+    /// Runs `case` once per data row. A case returns the response status it got, which is
+    /// what the row's result records.
+    pub async fn run<F, Fut>(&self, collector: &TestCollector, mut case: F) -> Result<()>
+    where
+        F: FnMut(DataRow) -> Fut,
+        Fut: Future<Output = Result<u16>>,
+    {
+        let rows = load_table(&self.data_path)?;
+        let total = rows.len();
+
+        for (position, row) in rows.into_iter().enumerate() {
+            let step_name = format!("{} [{}]", self.name, row.describe());
+            let parameters = row.to_map();
+            let start = Instant::now();
+
+            let result = match case(row).await {
+                Ok(status) => {
+                    println!("{} successful", step_name);
+                    TestResult::passed(
+                        &step_name,
+                        &self.api_name,
+                        &self.method,
+                        &self.endpoint,
+                        status,
+                        start.elapsed().as_millis() as u64,
+                    )
+                }
+                Err(e) => {
+                    println!("{} failed - {}", step_name, e);
+                    TestResult::failed(
+                        &step_name,
+                        &self.api_name,
+                        &self.method,
+                        &self.endpoint,
+                        error_status(&e),
+                        start.elapsed().as_millis() as u64,
+                        e.to_string(),
+                    )
+                }
+            };
+            collector.record(result.with_parameters(parameters));
+
+            if position + 1 < total && !self.pacing.is_zero() {
+                tokio::time::sleep(self.pacing).await;
+            }
+        }
+
+        Ok(())
+    }
+}
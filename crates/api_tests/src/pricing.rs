@@ -0,0 +1,441 @@
+use crate::api_context::ApiContext;
+use crate::request_response::Invoice_request_response::pos_items_response::PosItem;
+use anyhow::{bail, Context, Result};
+use lib_test_helpers::config::get_config;
+use serde_json::Value;
+
+/// Amounts the backend reports are compared to the paisa.
+const TOLERANCE_PAISE: i64 = 1;
+
+/// Money in integer paise, so sums of rounded lines never drift.
+pub type Paise = i64;
+
+pub fn to_paise(rupees: f64) -> Paise {
+    (rupees * 100.0).round() as Paise
+}
+
+pub fn to_rupees(paise: Paise) -> f64 {
+    paise as f64 / 100.0
+}
+
+/// `amount * percent / 100`, rounded half away from zero to the paisa.
+fn percent_of(amount: Paise, percent: f64) -> Paise {
+    (amount as f64 * percent / 100.0).round() as Paise
+}
+
+/// Intra-state supplies split GST into equal CGST and SGST; inter-state ones charge IGST.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Supply {
+    #[default]
+    IntraState,
+    InterState,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CartLine {
+    pub name: String,
+    pub unit_price: f64,
+    pub quantity: u32,
+    pub discount_percent: f64,
+    /// Highest discount percentage the item allows; larger requests are capped to it.
+    pub max_discount: f64,
+    pub tax_percentage: f64,
+}
+
+impl CartLine {
+    pub fn new(name: impl Into<String>, unit_price: f64, quantity: u32) -> Self {
+        Self {
+            name: name.into(),
+            unit_price,
+            quantity,
+            discount_percent: 0.0,
+            max_discount: 100.0,
+            tax_percentage: 0.0,
+        }
+    }
+
+    pub fn from_item(item: &PosItem, quantity: u32) -> Self {
+        Self {
+            max_discount: item.max_discount,
+            tax_percentage: item.tax_percentage,
+            ..Self::new(item.item_name.clone(), item.price, quantity)
+        }
+    }
+
+    pub fn unit_price(mut self, price: f64) -> Self {
+        self.unit_price = price;
+        self
+    }
+
+    pub fn discount(mut self, percent: f64) -> Self {
+        self.discount_percent = percent;
+        self
+    }
+
+    pub fn applied_discount_percent(&self) -> f64 {
+        self.discount_percent.clamp(0.0, self.max_discount.max(0.0))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LineTotals {
+    pub mrp: Paise,
+    pub discount: Paise,
+    pub taxable: Paise,
+    pub cgst: Paise,
+    pub sgst: Paise,
+    pub igst: Paise,
+    pub total: Paise,
+}
+
+impl LineTotals {
+    pub fn gst(&self) -> Paise {
+        self.cgst + self.sgst + self.igst
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CartTotals {
+    pub lines: Vec<LineTotals>,
+    pub total_mrp: Paise,
+    pub total_discount: Paise,
+    pub total_taxable: Paise,
+    pub cgst: Paise,
+    pub sgst: Paise,
+    pub igst: Paise,
+    pub bill_total: Paise,
+}
+
+impl CartTotals {
+    pub fn total_gst(&self) -> Paise {
+        self.cgst + self.sgst + self.igst
+    }
+}
+
+/// Independent model of POS cart pricing: each line is MRP x quantity, less its (capped)
+/// discount, plus GST on the discounted amount. Every line amount is rounded to the paisa
+/// before the bill is summed, the way the invoice prints it.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PricingOracle {
+    supply: Supply,
+    /// Prices already include GST, which is then backed out of the taxable amount.
+    tax_inclusive: bool,
+}
+
+impl PricingOracle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Supply type and price convention of the fixture POS node.
+    pub fn from_config() -> Self {
+        let fixtures = &get_config().fixtures;
+        Self::new()
+            .supply(if fixtures.inter_state_supply {
+                Supply::InterState
+            } else {
+                Supply::IntraState
+            })
+            .tax_inclusive(fixtures.tax_inclusive_prices)
+    }
+
+    pub fn supply(mut self, supply: Supply) -> Self {
+        self.supply = supply;
+        self
+    }
+
+    pub fn tax_inclusive(mut self, inclusive: bool) -> Self {
+        self.tax_inclusive = inclusive;
+        self
+    }
+
+    pub fn line(&self, line: &CartLine) -> LineTotals {
+        let mrp = to_paise(line.unit_price) * line.quantity as Paise;
+        let discount = percent_of(mrp, line.applied_discount_percent());
+        let discounted = mrp - discount;
+        let rate = line.tax_percentage.max(0.0);
+
+        let taxable = if self.tax_inclusive {
+            (discounted as f64 * 100.0 / (100.0 + rate)).round() as Paise
+        } else {
+            discounted
+        };
+        let (cgst, sgst, igst) = match self.supply {
+            Supply::IntraState => {
+                let half = percent_of(taxable, rate / 2.0);
+                (half, half, 0)
+            }
+            Supply::InterState => (0, 0, percent_of(taxable, rate)),
+        };
+        let gst = cgst + sgst + igst;
+        let total = if self.tax_inclusive {
+            // Back-calculated GST can be off by a paisa; the customer still pays the price.
+            discounted
+        } else {
+            taxable + gst
+        };
+
+        LineTotals {
+            mrp,
+            discount,
+            taxable,
+            cgst,
+            sgst,
+            igst,
+            total,
+        }
+    }
+
+    pub fn cart(&self, lines: &[CartLine]) -> CartTotals {
+        let lines: Vec<LineTotals> = lines.iter().map(|l| self.line(l)).collect();
+        let sum = |f: fn(&LineTotals) -> Paise| lines.iter().map(f).sum::<Paise>();
+        CartTotals {
+            total_mrp: sum(|l| l.mrp),
+            total_discount: sum(|l| l.discount),
+            total_taxable: sum(|l| l.taxable),
+            cgst: sum(|l| l.cgst),
+            sgst: sum(|l| l.sgst),
+            igst: sum(|l| l.igst),
+            bill_total: sum(|l| l.total),
+            lines,
+        }
+    }
+}
+
+/// First of `keys` present on `json` as a number or numeric string.
+fn amount(json: &Value, keys: &[&str]) -> Option<(String, f64)> {
+    keys.iter().find_map(|key| {
+        let value = json.get(*key)?;
+        let number = value
+            .as_f64()
+            .or_else(|| value.as_str().and_then(|s| s.trim().parse().ok()))?;
+        Some((key.to_string(), number))
+    })
+}
+
+fn compare(diffs: &mut Vec<String>, what: &str, expected: Paise, actual: Option<(String, f64)>) {
+    if let Some((key, actual)) = actual {
+        let actual = to_paise(actual);
+        if (actual - expected).abs() > TOLERANCE_PAISE {
+            diffs.push(format!(
+                "{} ({}): expected {:.2}, got {:.2}",
+                what,
+                key,
+                to_rupees(expected),
+                to_rupees(actual)
+            ));
+        }
+    }
+}
+
+/// Differences between the oracle and a cart or checkout response body. Fields the
+/// response doesn't carry are skipped; at least one total must be present.
+pub fn totals_mismatches(expected: &CartTotals, actual: &Value) -> Result<Vec<String>> {
+    let totals = [
+        (
+            "total MRP",
+            expected.total_mrp,
+            &["total_mrp", "totalMrp"][..],
+        ),
+        (
+            "total discount",
+            expected.total_discount,
+            &["total_discount", "totalDiscount"][..],
+        ),
+        (
+            "total GST",
+            expected.total_gst(),
+            &["total_gst", "totalGst", "gst_amount"][..],
+        ),
+        (
+            "bill total",
+            expected.bill_total,
+            &["bill_total", "billTotal", "grand_total", "total_amount"][..],
+        ),
+    ];
+    if totals
+        .iter()
+        .all(|(_, _, keys)| amount(actual, keys).is_none())
+    {
+        bail!("Response has none of the cart totals: {}", actual);
+    }
+
+    let mut diffs = Vec::new();
+    for (what, value, keys) in totals {
+        compare(&mut diffs, what, value, amount(actual, keys));
+    }
+
+    let rows = actual
+        .get("data")
+        .and_then(Value::as_array)
+        .cloned()
+        .unwrap_or_default();
+    for (i, (line, row)) in expected.lines.iter().zip(&rows).enumerate() {
+        let label = |what: &str| format!("line {} {}", i + 1, what);
+        compare(
+            &mut diffs,
+            &label("discount"),
+            line.discount,
+            amount(row, &["discount_amount"]),
+        );
+        compare(
+            &mut diffs,
+            &label("GST"),
+            line.gst(),
+            amount(row, &["gst_amount", "tax_amount"]),
+        );
+        compare(
+            &mut diffs,
+            &label("total"),
+            line.total,
+            amount(row, &["total_price", "final_price", "total"]),
+        );
+    }
+    Ok(diffs)
+}
+
+pub fn assert_totals(expected: &CartTotals, actual: &Value) -> Result<()> {
+    let diffs = totals_mismatches(expected, actual)?;
+    if !diffs.is_empty() {
+        bail!(
+            "Cart arithmetic does not match the pricing oracle:\n  {}",
+            diffs.join("\n  ")
+        );
+    }
+    Ok(())
+}
+
+/// Oracle totals for the cart last returned by the update-cart API, taking each item's tax
+/// rate and discount cap from the POS item list and its price, quantity and discount from
+/// the cart.
+pub fn expected_cart(ctx: &ApiContext) -> Result<CartTotals> {
+    let cart = ctx.get_raw("update_pos_cart_item", "data")?;
+    let items: Vec<PosItem> =
+        serde_json::from_value(ctx.get_raw("pos_items_all", "data")?["data"].clone())?;
+
+    let rows = cart["data"]["data"]
+        .as_array()
+        .context("Cart response has no data.data items")?;
+    let lines = rows
+        .iter()
+        .map(|row| {
+            let item = items
+                .iter()
+                .find(|item| row["node_item_id"].as_str() == Some(item.uuid.as_str()))
+                .with_context(|| format!("Cart row is not a known POS item: {}", row))?;
+            let quantity = amount(row, &["quantity"]).map_or(1, |(_, q)| q.round() as u32);
+            let mut line = CartLine::from_item(item, quantity);
+            if let Some((_, price)) = amount(row, &["price", "unit_price"]) {
+                line = line.unit_price(price);
+            }
+            if let Some((_, discount)) = amount(row, &["discount_percentage", "discount_percent"]) {
+                line = line.discount(discount);
+            }
+            Ok(line)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(PricingOracle::from_config().cart(&lines))
+}
+
+/// Checks the stored cart response against the oracle and returns the expected totals.
+pub fn verify_cart(ctx: &ApiContext) -> Result<CartTotals> {
+    let expected = expected_cart(ctx)?;
+    let cart = ctx.get_raw("update_pos_cart_item", "data")?;
+    assert_totals(&expected, &cart["data"])?;
+    Ok(expected)
+}
+
+/// Checks the totals the checkout response reports against the oracle for the cart that was
+/// checked out.
+pub fn verify_checkout(ctx: &ApiContext) -> Result<CartTotals> {
+    let expected = expected_cart(ctx)?;
+    let checkout = ctx.get_raw("pos_checkout", "data")?;
+    assert_totals(&expected, &checkout["data"])?;
+    Ok(expected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn taxed(price: f64, quantity: u32, tax: f64) -> CartLine {
+        CartLine {
+            tax_percentage: tax,
+            ..CartLine::new("item", price, quantity)
+        }
+    }
+
+    #[test]
+    fn discount_is_capped_at_the_item_maximum() {
+        let line = CartLine {
+            max_discount: 10.0,
+            ..CartLine::new("item", 100.0, 2)
+        }
+        .discount(50.0);
+        let totals = PricingOracle::new().line(&line);
+        assert_eq!(line.applied_discount_percent(), 10.0);
+        assert_eq!(totals.mrp, 20_000);
+        assert_eq!(totals.discount, 2_000);
+        assert_eq!(totals.total, 18_000);
+    }
+
+    #[test]
+    fn intra_state_splits_gst_into_cgst_and_sgst() {
+        let totals = PricingOracle::new().line(&taxed(100.0, 1, 18.0));
+        assert_eq!((totals.cgst, totals.sgst, totals.igst), (900, 900, 0));
+        assert_eq!(totals.total, 11_800);
+    }
+
+    #[test]
+    fn inter_state_charges_igst_only() {
+        let totals = PricingOracle::new()
+            .supply(Supply::InterState)
+            .line(&taxed(100.0, 1, 18.0));
+        assert_eq!((totals.cgst, totals.sgst, totals.igst), (0, 0, 1_800));
+        assert_eq!(totals.total, 11_800);
+    }
+
+    #[test]
+    fn tax_inclusive_prices_back_out_gst() {
+        let totals = PricingOracle::new()
+            .tax_inclusive(true)
+            .line(&taxed(118.0, 1, 18.0));
+        assert_eq!(totals.taxable, 10_000);
+        assert_eq!(totals.gst(), 1_800);
+        assert_eq!(totals.total, 11_800);
+    }
+
+    #[test]
+    fn tax_inclusive_total_is_the_price_even_when_gst_rounds() {
+        // 100 / 1.05 = 95.238..., so GST backs out to 4.76 on either split.
+        let totals = PricingOracle::new()
+            .tax_inclusive(true)
+            .line(&taxed(100.0, 1, 5.0));
+        assert_eq!(totals.taxable, 9_524);
+        assert_eq!(totals.total, 10_000);
+    }
+
+    #[test]
+    fn half_paisa_rounds_away_from_zero_per_line() {
+        // 5% of 10.10 is 50.5 paise: IGST rounds up, each 2.5% half rounds 25.25 down.
+        let line = taxed(10.10, 1, 5.0);
+        let inter = PricingOracle::new().supply(Supply::InterState).line(&line);
+        let intra = PricingOracle::new().line(&line);
+        assert_eq!(inter.igst, 51);
+        assert_eq!((intra.cgst, intra.sgst), (25, 25));
+
+        // Rounded per line, so two such lines bill 1.02 of IGST rather than 1.01.
+        let cart = PricingOracle::new()
+            .supply(Supply::InterState)
+            .cart(&[line.clone(), line]);
+        assert_eq!(cart.igst, 102);
+    }
+
+    #[test]
+    fn discount_rounds_half_paisa_up() {
+        let totals = PricingOracle::new().line(&CartLine::new("item", 10.10, 1).discount(5.0));
+        assert_eq!(totals.discount, 51);
+        assert_eq!(totals.total, 959);
+    }
+}
@@ -0,0 +1,258 @@
+use crate::reporting::TestResult;
+use proptest::strategy::{Strategy, ValueTree};
+use proptest::test_runner::{Config, RngAlgorithm, TestRng, TestRunner};
+use std::fmt::Debug;
+use std::future::Future;
+use std::time::Instant;
+
+/// A failing case after shrinking, with the reason its smallest version failed.
+#[derive(Debug, Clone)]
+pub struct Counterexample<V> {
+    pub value: V,
+    pub reason: String,
+}
+
+/// What a property run against the backend saw.
+#[derive(Debug, Clone)]
+pub struct PropertyRun<V> {
+    pub cases: u32,
+    pub cases_run: u32,
+    pub failure: Option<Counterexample<V>>,
+    /// The run stopped early because a case could not be executed at all.
+    pub error: Option<String>,
+}
+
+impl<V> PropertyRun<V> {
+    /// e.g. "stopped after 12 of 50 cases: connection refused"
+    pub fn stop_reason(&self) -> Option<String> {
+        self.error.as_ref().map(|error| {
+            format!(
+                "stopped after {} of {} cases: {}",
+                self.cases_run, self.cases, error
+            )
+        })
+    }
+}
+
+pub fn test_rng(seed: u64) -> TestRng {
+    let mut bytes = [0u8; 32];
+    bytes[..8].copy_from_slice(&seed.to_le_bytes());
+    TestRng::from_seed(RngAlgorithm::ChaCha, &bytes)
+}
+
+/// Generates `config.cases` values from `strategy` with the run seed and awaits `test` on each,
+/// shrinking the first failure the way proptest's `TestRunner` does. Unlike `TestRunner::run`
+/// the test is async, so cases run on whatever runtime the caller is on.
+///
+/// `test` returns `Ok(None)` for a pass, `Ok(Some(reason))` for a failure and `Err` when the
+/// case could not run (transport error, setup failure), which ends the run.
+pub async fn check<S, F, Fut>(
+    strategy: &S,
+    config: Config,
+    seed: u64,
+    mut test: F,
+) -> PropertyRun<S::Value>
+where
+    S: Strategy,
+    F: FnMut(S::Value) -> Fut,
+    Fut: Future<Output = anyhow::Result<Option<String>>>,
+{
+    let mut run = PropertyRun {
+        cases: config.cases,
+        cases_run: 0,
+        failure: None,
+        error: None,
+    };
+    let max_shrink_iters = config.max_shrink_iters;
+    let mut runner = TestRunner::new_with_rng(config, test_rng(seed));
+
+    for _ in 0..run.cases {
+        let mut tree = match strategy.new_tree(&mut runner) {
+            Ok(tree) => tree,
+            Err(reason) => {
+                run.error = Some(format!("could not generate a case: {}", reason));
+                return run;
+            }
+        };
+        let reason = match test(tree.current()).await {
+            Ok(None) => {
+                run.cases_run += 1;
+                continue;
+            }
+            Ok(Some(reason)) => reason,
+            Err(e) => {
+                run.error = Some(e.to_string());
+                return run;
+            }
+        };
+        run.cases_run += 1;
+
+        let mut failure = Counterexample {
+            value: tree.current(),
+            reason,
+        };
+        if tree.simplify() {
+            for _ in 0..max_shrink_iters {
+                let advanced = match test(tree.current()).await {
+                    Ok(Some(reason)) => {
+                        failure = Counterexample {
+                            value: tree.current(),
+                            reason,
+                        };
+                        tree.simplify()
+                    }
+                    Ok(None) => tree.complicate(),
+                    // The original failure still stands; shrinking just stops here.
+                    Err(_) => false,
+                };
+                if !advanced {
+                    break;
+                }
+            }
+        }
+        run.failure = Some(failure);
+        return run;
+    }
+    run
+}
+
+/// A model-based check: random command sequences replayed against the backend, each
+/// compared step by step with an in-memory model.
+pub struct ModelCheck<'a> {
+    /// e.g. "Stage model"
+    pub model: &'a str,
+    pub step_name: String,
+    pub api_name: &'a str,
+    pub method: &'a str,
+    pub endpoint: &'a str,
+    /// Message prefix when the backend and the model disagree.
+    pub disagreement: &'a str,
+    pub cases: u32,
+    /// Every shrink step replays a whole sequence against the backend, so keep this short.
+    pub max_shrink_iters: u32,
+}
+
+impl ModelCheck<'_> {
+    /// Runs `sequence` on every generated case. It returns the step log, or `Err(log)` ending
+    /// with the step where the backend disagreed with the model. The result is grouped under
+    /// "<api> - Model-based"; callers add their own metrics and record it.
+    pub async fn run<S, F, Fut>(&self, strategy: &S, mut sequence: F) -> TestResult
+    where
+        S: Strategy,
+        S::Value: Debug,
+        F: FnMut(S::Value) -> Fut,
+        Fut: Future<Output = anyhow::Result<Result<Vec<String>, Vec<String>>>>,
+    {
+        let seed = lib_test_helpers::seed::current_seed();
+        let config = Config {
+            cases: self.cases,
+            failure_persistence: None,
+            max_shrink_iters: self.max_shrink_iters,
+            ..Config::default()
+        };
+
+        let start = Instant::now();
+        let run = check(strategy, config, seed, |value| {
+            let log = sequence(value);
+            async move { Ok(log.await?.err().map(|log| log.join("\n"))) }
+        })
+        .await;
+        let duration_ms = start.elapsed().as_millis() as u64;
+
+        let result = match (run.stop_reason(), run.failure) {
+            (_, Some(failure)) => {
+                let reason = failure.reason.lines().last().unwrap_or_default();
+                println!("{} failed - {}", self.step_name, reason);
+                TestResult::failed(
+                    &self.step_name,
+                    self.api_name,
+                    self.method,
+                    self.endpoint,
+                    None,
+                    duration_ms,
+                    format!("{} (seed {}): {}", self.disagreement, seed, reason),
+                )
+                .with_request_body(format!("{:#?}\n\n{}", failure.value, failure.reason))
+            }
+            (Some(stopped), None) => {
+                println!("{} failed - {} {}", self.step_name, self.model, stopped);
+                TestResult::failed(
+                    &self.step_name,
+                    self.api_name,
+                    self.method,
+                    self.endpoint,
+                    None,
+                    duration_ms,
+                    format!("{} {}", self.model, stopped),
+                )
+            }
+            (None, None) => {
+                println!("{} successful", self.step_name);
+                TestResult::passed(
+                    &self.step_name,
+                    self.api_name,
+                    self.method,
+                    self.endpoint,
+                    200,
+                    duration_ms,
+                )
+            }
+        };
+        result.with_group(format!("{} - Model-based", self.api_name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn config(cases: u32) -> Config {
+        Config {
+            cases,
+            failure_persistence: None,
+            ..Config::default()
+        }
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn shrinks_a_failure_to_the_boundary() {
+        let run = check(&(0u32..1000), config(200), 7, |n| async move {
+            Ok((n >= 100).then(|| format!("{} is too big", n)))
+        })
+        .await;
+
+        let failure = run.failure.expect("some case should fail");
+        assert_eq!(failure.value, 100);
+        assert_eq!(failure.reason, "100 is too big");
+        assert!(run.error.is_none());
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn a_case_that_cannot_run_stops_the_run() {
+        let mut calls = 0;
+        let run = check(&any::<u8>(), config(10), 7, |_| {
+            calls += 1;
+            let fail = calls == 3;
+            async move {
+                anyhow::ensure!(!fail, "connection refused");
+                Ok(None)
+            }
+        })
+        .await;
+
+        assert_eq!(run.cases_run, 2);
+        assert!(run.failure.is_none());
+        assert_eq!(
+            run.stop_reason().as_deref(),
+            Some("stopped after 2 of 10 cases: connection refused")
+        );
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn passing_runs_every_case() {
+        let run = check(&any::<u8>(), config(25), 7, |_| async { Ok(None) }).await;
+        assert_eq!(run.cases_run, 25);
+        assert!(run.failure.is_none() && run.error.is_none());
+    }
+}
@@ -0,0 +1 @@
+pub use lib_test_helpers::reporting::*;
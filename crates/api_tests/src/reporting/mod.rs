@@ -1,9 +0,0 @@
-pub mod html_reporter;
-pub mod reporter;
-pub mod test_collector;
-pub mod types;
-
-pub use html_reporter::HtmlReporter;
-pub use reporter::{ConsoleReporter, JUnitReporter, JsonReporter, Reporter};
-pub use test_collector::TestCollector;
-pub use types::{ApiTestResult, TestReport, TestStatus};
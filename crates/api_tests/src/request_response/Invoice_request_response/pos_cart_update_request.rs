@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(untagged)]
 pub enum Value {
     String(String),
@@ -9,7 +9,7 @@ pub enum Value {
     Array(Vec<Value>),
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct UpdatePosCartItemRequest {
     pub customer_id: Value,
     pub customer_type: Value,
@@ -17,7 +17,7 @@ pub struct UpdatePosCartItemRequest {
     pub cart_items: Vec<Items>,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(untagged)]
 pub enum Items {
     New {
@@ -1,7 +1,7 @@
 use serde::Deserialize;
 use serde::Serialize;
 
-#[derive(Serialize)]
+#[derive(Serialize, Debug, Clone)]
 pub struct LeadCreateRequest {
     pub country_code: String,
     pub lead_name: String,
@@ -31,3 +31,23 @@ pub struct CreatedLeadDetails {
     pub uuid: String,
     pub id: i32,
 }
+
+/// One row of the lead listing. The listing has used both the create-request field names and
+/// shorter ones, so both are accepted.
+#[derive(Deserialize, Debug, Clone)]
+pub struct LeadSummary {
+    #[serde(default)]
+    pub uuid: String,
+    #[serde(alias = "lead_name", alias = "full_name", default)]
+    pub name: String,
+    #[serde(alias = "lead_phone", alias = "mobile_number", default)]
+    pub phone: String,
+    #[serde(alias = "lead_email", default)]
+    pub email: Option<String>,
+    #[serde(alias = "lead_source", default)]
+    pub source: Option<String>,
+    #[serde(alias = "lead_source_category", default)]
+    pub source_category: Option<String>,
+    #[serde(default)]
+    pub campaign_name: Option<String>,
+}
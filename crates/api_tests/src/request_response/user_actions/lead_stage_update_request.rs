@@ -23,7 +23,7 @@ pub struct LeadStageUpdateResponse {
     pub data: bool,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone)]
 pub struct LeadStages {
     pub lead_stage_1_uuid: String,
     pub lead_stage_2_uuid: String,
@@ -33,6 +33,20 @@ pub struct LeadStages {
     pub lead_stage_6_uuid: String,
 }
 
+impl LeadStages {
+    /// Stage UUIDs in pipeline order; unused stages are empty strings.
+    pub fn uuids(&self) -> [&str; 6] {
+        [
+            &self.lead_stage_1_uuid,
+            &self.lead_stage_2_uuid,
+            &self.lead_stage_3_uuid,
+            &self.lead_stage_4_uuid,
+            &self.lead_stage_5_uuid,
+            &self.lead_stage_6_uuid,
+        ]
+    }
+}
+
 #[derive(Deserialize)]
 pub struct ServiceUuid {
     pub service_uuid: String,
@@ -1,8 +1,10 @@
 use chrono::{Datelike, Timelike, Utc};
 use fake::{
-    faker::{address::en::*, internet::en::*, name::en::*, phone_number::en::*},
+    faker::{address::en::*, internet::en::*, name::en::*},
     Fake,
 };
+use lib_test_helpers::india_data::IndiaDataFactory;
+use lib_test_helpers::seed::with_rng;
 use rand::Rng;
 use std::time::Duration;
 
@@ -10,36 +12,38 @@ pub struct TestDataFactory;
 
 impl TestDataFactory {
     pub fn email() -> String {
-        SafeEmail().fake()
+        with_rng(|rng| SafeEmail().fake_with_rng(rng))
     }
 
     pub fn phone() -> String {
-        PhoneNumber().fake()
+        IndiaDataFactory::mobile_number()
     }
 
     pub fn name() -> String {
-        Name().fake()
+        with_rng(|rng| Name().fake_with_rng(rng))
     }
 
     pub fn first_name() -> String {
-        FirstName().fake()
+        with_rng(|rng| FirstName().fake_with_rng(rng))
     }
 
     pub fn last_name() -> String {
-        LastName().fake()
+        with_rng(|rng| LastName().fake_with_rng(rng))
     }
 
     pub fn address() -> String {
-        format!(
-            "{} {}",
-            BuildingNumber().fake::<String>(),
-            StreetName().fake::<String>()
-        )
+        with_rng(|rng| {
+            format!(
+                "{} {}",
+                BuildingNumber().fake_with_rng::<String, _>(rng),
+                StreetName().fake_with_rng::<String, _>(rng)
+            )
+        })
     }
 
     pub fn time_fake() -> String {
         let max_days_back = 30;
-        let seconds_back = rand::thread_rng().gen_range(0..(max_days_back * 24 * 3600));
+        let seconds_back = with_rng(|rng| rng.gen_range(0..(max_days_back * 24 * 3600)));
 
         let now = Utc::now().naive_utc();
         let t = now - Duration::from_secs(seconds_back);
@@ -55,23 +59,28 @@ impl TestDataFactory {
         )
     }
 
+    pub fn datetime_from_now(days: i64) -> String {
+        (Utc::now().naive_utc() + chrono::Duration::days(days))
+            .format("%Y-%m-%d %H:%M:%S")
+            .to_string()
+    }
+
     pub fn city() -> String {
-        CityName().fake()
+        with_rng(|rng| CityName().fake_with_rng(rng))
     }
 
     pub fn random_string(length: usize) -> String {
         use rand::distributions::Alphanumeric;
-        use rand::Rng;
 
-        rand::thread_rng()
-            .sample_iter(&Alphanumeric)
-            .take(length)
-            .map(char::from)
-            .collect()
+        with_rng(|rng| {
+            rng.sample_iter(&Alphanumeric)
+                .take(length)
+                .map(char::from)
+                .collect()
+        })
     }
 
     pub fn random_number(min: i32, max: i32) -> i32 {
-        let mut rng = rand::thread_rng();
-        rng.gen_range(min..=max)
+        with_rng(|rng| rng.gen_range(min..=max))
     }
 }
@@ -1,7 +1,9 @@
 pub mod assertions;
 pub mod factories;
+pub mod request_factories;
 pub mod retry;
 
 pub use assertions::*;
 pub use factories::*;
+pub use request_factories::*;
 pub use retry::*;
@@ -0,0 +1,425 @@
+use crate::pricing::{to_rupees, CartTotals};
+use crate::request_response::user_actions::elder_data_edit::ElderDataEditRequest;
+use crate::request_response::user_actions::lead::LeadCreateRequest;
+use crate::request_response::Invoice_request_response::create_invoice_request::{
+    CartItem, CreateInvoiceRequest, PaymentInfo, ShareInfo,
+};
+use crate::request_response::Invoice_request_response::pos_cart_update_request::{
+    Items as CartUpdateItem, UpdatePosCartItemRequest, Value as RequestValue,
+};
+use crate::request_response::Invoice_request_response::pos_checkout_request::{
+    Items as CheckoutItem, Payment, PosCheckoutRequest, ShareInvoice,
+};
+use crate::utils::factories::TestDataFactory;
+use lib_test_helpers::config::{get_config, Fixtures};
+
+macro_rules! overrides {
+    ($factory:ident => $target:ident { $($field:ident: $ty:ty),* $(,)? }) => {
+        #[allow(non_snake_case)]
+        impl $factory {
+            $(
+                pub fn $field(mut self, value: impl Into<$ty>) -> Self {
+                    self.$target.$field = value.into();
+                    self
+                }
+            )*
+        }
+    };
+}
+
+fn fixtures() -> &'static Fixtures {
+    &get_config().fixtures
+}
+
+fn notification_email() -> String {
+    fixtures()
+        .notification_email
+        .clone()
+        .unwrap_or_else(TestDataFactory::email)
+}
+
+fn notification_mobile() -> String {
+    fixtures()
+        .notification_mobile
+        .clone()
+        .unwrap_or_else(TestDataFactory::phone)
+}
+
+pub struct CreateInvoiceRequestFactory {
+    request: CreateInvoiceRequest,
+}
+
+impl CreateInvoiceRequestFactory {
+    pub fn new() -> Self {
+        let now = TestDataFactory::datetime_from_now(0);
+        Self {
+            request: CreateInvoiceRequest {
+                emi_status: 0,
+                pos_node_id: fixtures().pos_node_id.clone(),
+                cart_id: String::new(),
+                pos_receipt_id: String::new(),
+                customer_invoice_name: String::new(),
+                cart_items: vec![],
+                cart_type: "service".to_string(),
+                concierge_ticket: vec![],
+                payment: PaymentInfo {
+                    amount: 10,
+                    method: "link".to_string(),
+                    send_link_to_email: notification_email(),
+                    send_link_to_mobile: notification_mobile(),
+                },
+                share_invoice: vec![],
+                start_date: now.clone(),
+                end_date: now.clone(),
+                invoice_date: now,
+                invoice_payment_due_date: TestDataFactory::datetime_from_now(7),
+                place_of_supply: fixtures().place_of_supply.clone(),
+                total_discount: 0,
+                total_mrp: 0.0,
+                total_gst: 0.0,
+                bill_total: 0.0,
+                source: "wms_profile".to_string(),
+                lead_uuid: None,
+            },
+        }
+    }
+
+    pub fn cart_item(mut self, cart_item_id: impl Into<String>) -> Self {
+        self.request.cart_items.push(CartItem {
+            cart_item_id: cart_item_id.into(),
+            discount_percent: 0,
+            description: None,
+        });
+        self
+    }
+
+    pub fn share_with(mut self, phone_number: impl Into<String>, email: impl Into<String>) -> Self {
+        self.request.share_invoice.push(ShareInfo {
+            phone_number: phone_number.into(),
+            email: email.into(),
+        });
+        self
+    }
+
+    pub fn payment_amount(mut self, amount: i32) -> Self {
+        self.request.payment.amount = amount;
+        self
+    }
+
+    pub fn build(self) -> CreateInvoiceRequest {
+        self.request
+    }
+}
+
+impl Default for CreateInvoiceRequestFactory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+overrides!(CreateInvoiceRequestFactory => request {
+    emi_status: i32,
+    pos_node_id: String,
+    cart_id: String,
+    pos_receipt_id: String,
+    customer_invoice_name: String,
+    cart_items: Vec<CartItem>,
+    cart_type: String,
+    payment: PaymentInfo,
+    share_invoice: Vec<ShareInfo>,
+    start_date: String,
+    end_date: String,
+    invoice_date: String,
+    invoice_payment_due_date: String,
+    place_of_supply: String,
+    total_discount: i32,
+    total_mrp: f64,
+    total_gst: f64,
+    bill_total: f64,
+    source: String,
+    lead_uuid: Option<String>,
+});
+
+pub struct PosCheckoutRequestFactory {
+    request: PosCheckoutRequest,
+}
+
+impl PosCheckoutRequestFactory {
+    pub fn new() -> Self {
+        let now = TestDataFactory::datetime_from_now(0);
+        let email = notification_email();
+        let mobile = notification_mobile();
+        Self {
+            request: PosCheckoutRequest {
+                pos_node_id: fixtures().pos_node_id.clone(),
+                cart_id: String::new(),
+                posReceipt_id: String::new(),
+                customer_invoice_name: String::new(),
+                cart_items: vec![],
+                cart_type: "service".to_string(),
+                concierge_ticket: vec![],
+                payment: Payment {
+                    amount: 0.0,
+                    method: "link".to_string(),
+                    send_link_to_email: email.clone(),
+                    send_link_to_mobile: mobile.clone(),
+                },
+                share_invoice: vec![ShareInvoice {
+                    phone_number: mobile,
+                    email,
+                }],
+                start_date: now.clone(),
+                end_date: now.clone(),
+                invoice_date: now,
+                invoice_payment_due_date: TestDataFactory::datetime_from_now(7),
+                place_of_supply: fixtures().place_of_supply.clone(),
+                total_discount: 0.0,
+                total_mrp: 0.0,
+                total_gst: 0.0,
+                bill_total: 0.0,
+                source: "rust_auto".to_string(),
+                lead_uuid: None,
+            },
+        }
+    }
+
+    pub fn cart_item(mut self, cart_item_id: impl Into<String>) -> Self {
+        self.request.cart_items.push(CheckoutItem {
+            cart_item_id: cart_item_id.into(),
+            discount_percent: 0,
+            description: String::new(),
+        });
+        self
+    }
+
+    pub fn payment_amount(mut self, amount: f64) -> Self {
+        self.request.payment.amount = amount;
+        self
+    }
+
+    /// Fills the bill totals and payment amount from the pricing oracle.
+    pub fn totals(mut self, totals: &CartTotals) -> Self {
+        self.request.total_mrp = to_rupees(totals.total_mrp);
+        self.request.total_discount = to_rupees(totals.total_discount);
+        self.request.total_gst = to_rupees(totals.total_gst());
+        self.request.bill_total = to_rupees(totals.bill_total);
+        self.request.payment.amount = to_rupees(totals.bill_total);
+        self
+    }
+
+    pub fn build(self) -> PosCheckoutRequest {
+        self.request
+    }
+}
+
+impl Default for PosCheckoutRequestFactory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+overrides!(PosCheckoutRequestFactory => request {
+    pos_node_id: String,
+    cart_id: String,
+    posReceipt_id: String,
+    customer_invoice_name: String,
+    cart_items: Vec<CheckoutItem>,
+    cart_type: String,
+    payment: Payment,
+    share_invoice: Vec<ShareInvoice>,
+    start_date: String,
+    end_date: String,
+    invoice_date: String,
+    invoice_payment_due_date: String,
+    place_of_supply: String,
+    total_discount: f64,
+    total_mrp: f64,
+    total_gst: f64,
+    bill_total: f64,
+    source: String,
+    lead_uuid: Option<String>,
+});
+
+pub struct LeadCreateRequestFactory {
+    request: LeadCreateRequest,
+}
+
+impl LeadCreateRequestFactory {
+    pub fn new() -> Self {
+        Self {
+            request: LeadCreateRequest {
+                country_code: get_config().country_code.clone(),
+                lead_name: TestDataFactory::name(),
+                lead_phone: TestDataFactory::phone(),
+                lead_email: TestDataFactory::email(),
+                lead_source_category: "test".to_string(),
+                lead_source: "rust_auto".to_string(),
+                campaign_name: "test".to_string(),
+                lead_owner_uuid: fixtures().lead_owner_uuid.clone().unwrap_or_default(),
+            },
+        }
+    }
+
+    pub fn build(self) -> LeadCreateRequest {
+        self.request
+    }
+}
+
+impl Default for LeadCreateRequestFactory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+overrides!(LeadCreateRequestFactory => request {
+    country_code: String,
+    lead_name: String,
+    lead_phone: String,
+    lead_email: String,
+    lead_source_category: String,
+    lead_source: String,
+    campaign_name: String,
+    lead_owner_uuid: String,
+});
+
+pub struct ElderDataEditRequestFactory {
+    request: ElderDataEditRequest,
+}
+
+impl ElderDataEditRequestFactory {
+    pub fn new() -> Self {
+        Self {
+            request: ElderDataEditRequest {
+                currentElderIdentifier: String::new(),
+                consumeraddress_uuid: String::new(),
+                phone_no: TestDataFactory::phone(),
+                is_nok_elder_calling: "elder".to_string(),
+                email: TestDataFactory::email(),
+                reasons_for_downloading_app: None,
+                first_name: TestDataFactory::first_name(),
+                age: Some(TestDataFactory::random_number(55, 95) as i8),
+                languages: None,
+                last_name: TestDataFactory::last_name(),
+                gender: TestDataFactory::random_number(1, 2) as i16,
+                current_medical_conditions: None,
+                pincode: None,
+                address_line_1: None,
+                address_line_2: None,
+                city: None,
+                state: None,
+                locality: None,
+                country: None,
+                new_selected_ep_uuid: None,
+                r#override: None,
+            },
+        }
+    }
+
+    pub fn build(self) -> ElderDataEditRequest {
+        self.request
+    }
+}
+
+impl Default for ElderDataEditRequestFactory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+overrides!(ElderDataEditRequestFactory => request {
+    currentElderIdentifier: String,
+    consumeraddress_uuid: String,
+    phone_no: String,
+    is_nok_elder_calling: String,
+    email: String,
+    reasons_for_downloading_app: Option<String>,
+    first_name: String,
+    age: Option<i8>,
+    languages: Option<String>,
+    last_name: String,
+    gender: i16,
+    current_medical_conditions: Option<String>,
+    pincode: Option<String>,
+    address_line_1: Option<String>,
+    address_line_2: Option<String>,
+    city: Option<String>,
+    state: Option<String>,
+    locality: Option<String>,
+    country: Option<String>,
+    new_selected_ep_uuid: Option<String>,
+    r#override: Option<String>,
+});
+
+pub struct UpdatePosCartItemRequestFactory {
+    request: UpdatePosCartItemRequest,
+}
+
+impl UpdatePosCartItemRequestFactory {
+    pub fn new() -> Self {
+        Self {
+            request: UpdatePosCartItemRequest {
+                customer_id: RequestValue::String(String::new()),
+                customer_type: RequestValue::String("registered".to_string()),
+                node_id: RequestValue::String(fixtures().pos_node_id.clone()),
+                cart_items: vec![],
+            },
+        }
+    }
+
+    pub fn customer_id(mut self, customer_id: impl Into<String>) -> Self {
+        self.request.customer_id = RequestValue::String(customer_id.into());
+        self
+    }
+
+    pub fn customer_type(mut self, customer_type: impl Into<String>) -> Self {
+        self.request.customer_type = RequestValue::String(customer_type.into());
+        self
+    }
+
+    pub fn node_id(mut self, node_id: impl Into<String>) -> Self {
+        self.request.node_id = RequestValue::String(node_id.into());
+        self
+    }
+
+    pub fn new_item(mut self, node_item_id: impl Into<String>, price: f64, quantity: f64) -> Self {
+        self.request.cart_items.push(CartUpdateItem::New {
+            node_item_id: RequestValue::String(node_item_id.into()),
+            price: RequestValue::Usize(price),
+            quantity: RequestValue::Usize(quantity),
+            discount_percentage: RequestValue::Usize(0.00),
+        });
+        self
+    }
+
+    pub fn update_item(
+        mut self,
+        uuid: impl Into<String>,
+        node_item_id: impl Into<String>,
+        price: f64,
+        quantity: i64,
+    ) -> Self {
+        self.request.cart_items.push(CartUpdateItem::Update {
+            uuid: RequestValue::String(uuid.into()),
+            node_item_id: RequestValue::String(node_item_id.into()),
+            price: RequestValue::Usize(price),
+            quantity: RequestValue::Isize(quantity),
+            discount_percentage: RequestValue::Usize(0.00),
+        });
+        self
+    }
+
+    pub fn cart_items(mut self, cart_items: Vec<CartUpdateItem>) -> Self {
+        self.request.cart_items = cart_items;
+        self
+    }
+
+    pub fn build(self) -> UpdatePosCartItemRequest {
+        self.request
+    }
+}
+
+impl Default for UpdatePosCartItemRequestFactory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
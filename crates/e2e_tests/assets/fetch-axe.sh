@@ -0,0 +1,15 @@
+#!/usr/bin/env bash
+# Downloads the pinned axe-core build that the accessibility audits inject into each page.
+# The E2E suite runs it on start-up when the script is missing; run it by hand (or in CI)
+# to fetch ahead of time. AXE_DEST overrides where it is saved. Bump AXE_VERSION deliberately: new rules can fail pages that passed before.
+set -euo pipefail
+
+AXE_VERSION="${AXE_VERSION:-4.10.2}"
+DEST="${AXE_DEST:-$(dirname "$0")/axe.min.js}"
+
+curl --fail --silent --show-error --location \
+    "https://cdn.jsdelivr.net/npm/axe-core@${AXE_VERSION}/axe.min.js" \
+    --output "${DEST}.tmp"
+grep -q "axe.version" "${DEST}.tmp" || { echo "Downloaded file is not axe-core" >&2; exit 1; }
+mv "${DEST}.tmp" "${DEST}"
+echo "axe-core ${AXE_VERSION} saved to ${DEST}"
@@ -0,0 +1,282 @@
+use lib_test_helpers::config::get_config;
+use lib_test_helpers::reporting::{A11yViolation, TestResult};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use std::sync::Mutex;
+use thirtyfour::prelude::*;
+
+const RUN_AXE: &str = r#"
+    const [context, options] = arguments;
+    return window.axe.run(context || document, options).then(results => ({
+        url: location.href,
+        violations: results.violations.map(v => ({
+            id: v.id,
+            impact: v.impact || 'minor',
+            help: v.help,
+            helpUrl: v.helpUrl,
+            targets: v.nodes.map(n => n.target.join(' ')),
+        })),
+    }));
+"#;
+
+// Audits run inside flows, which only see a WebDriver, so they are parked here per session
+// until the suite step that ran the flow records them.
+static AUDITS: Mutex<Vec<(String, A11yAudit)>> = Mutex::new(Vec::new());
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Impact {
+    Minor,
+    Moderate,
+    Serious,
+    Critical,
+}
+
+impl Impact {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "minor" => Some(Self::Minor),
+            "moderate" => Some(Self::Moderate),
+            "serious" => Some(Self::Serious),
+            "critical" => Some(Self::Critical),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Minor => "minor",
+            Self::Moderate => "moderate",
+            Self::Serious => "serious",
+            Self::Critical => "critical",
+        }
+    }
+}
+
+/// What part of the page to audit.
+#[derive(Debug, Clone, Copy)]
+pub enum Scope<'a> {
+    Page,
+    /// Only elements inside the first match of this CSS selector.
+    Css(&'a str),
+}
+
+impl Scope<'_> {
+    fn context(&self) -> Value {
+        match self {
+            Scope::Page => Value::Null,
+            Scope::Css(selector) => json!(selector),
+        }
+    }
+
+    fn label(&self) -> String {
+        match self {
+            Scope::Page => "page".to_string(),
+            Scope::Css(selector) => selector.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct A11yAudit {
+    pub url: String,
+    pub scope: String,
+    pub fail_on: Impact,
+    pub violations: Vec<A11yViolation>,
+    /// Set when the audit could not run, e.g. axe-core has not been fetched.
+    pub error: Option<String>,
+}
+
+impl A11yAudit {
+    /// Violations at or above the `fail_on` impact.
+    pub fn blocking(&self) -> Vec<&A11yViolation> {
+        self.violations
+            .iter()
+            .filter(|v| Impact::parse(&v.impact).unwrap_or(Impact::Minor) >= self.fail_on)
+            .collect()
+    }
+
+    pub fn to_result(&self) -> TestResult {
+        let name = format!("A11y: {} ({})", page_name(&self.url), self.scope);
+        let result = match &self.error {
+            Some(reason) => TestResult::ui_failed(&name, &self.url, 0, reason.clone()),
+            None => {
+                let blocking = self.blocking();
+                if blocking.is_empty() {
+                    TestResult::ui_passed(&name, &self.url, 0)
+                } else {
+                    let mut rules: Vec<&str> = blocking.iter().map(|v| v.rule.as_str()).collect();
+                    rules.sort_unstable();
+                    rules.dedup();
+                    TestResult::ui_failed(
+                        &name,
+                        &self.url,
+                        0,
+                        format!(
+                            "{} violation(s) at {} or above: {}",
+                            blocking.len(),
+                            self.fail_on.as_str(),
+                            rules.join(", ")
+                        ),
+                    )
+                }
+                .with_metric("a11y_violations", self.violations.len() as f64)
+                .with_metric("a11y_blocking", blocking.len() as f64)
+            }
+        };
+        result.with_a11y(self.violations.clone())
+    }
+}
+
+#[derive(Deserialize)]
+struct RawViolation {
+    id: String,
+    impact: String,
+    help: String,
+    #[serde(rename = "helpUrl")]
+    help_url: String,
+    targets: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct RawResults {
+    url: String,
+    violations: Vec<RawViolation>,
+}
+
+fn page_name(url: &str) -> &str {
+    url.split("://")
+        .nth(1)
+        .and_then(|rest| rest.find('/').map(|i| &rest[i..]))
+        .filter(|path| !path.is_empty())
+        .unwrap_or(url)
+}
+
+fn axe_options() -> Value {
+    let cfg = &get_config().a11y;
+    let mut options = json!({ "resultTypes": ["violations"] });
+    if !cfg.tags.is_empty() {
+        options["runOnly"] = json!({ "type": "tag", "values": cfg.tags });
+    }
+    if !cfg.disabled_rules.is_empty() {
+        options["rules"] = cfg
+            .disabled_rules
+            .iter()
+            .map(|rule| (rule.clone(), json!({ "enabled": false })))
+            .collect::<serde_json::Map<_, _>>()
+            .into();
+    }
+    options
+}
+
+/// Makes sure the configured axe-core script exists before any audit runs, fetching the pinned
+/// build with `assets/fetch-axe.sh` when it is missing.
+pub fn ensure_axe() -> anyhow::Result<()> {
+    let path = &get_config().a11y.script;
+    if Path::new(path).is_file() {
+        return Ok(());
+    }
+    let fetch = Path::new(env!("CARGO_MANIFEST_DIR")).join("assets/fetch-axe.sh");
+    println!("axe-core not found at {}, fetching it", path);
+    let reason = match Command::new("bash")
+        .arg(&fetch)
+        .env("AXE_DEST", path)
+        .status()
+    {
+        Ok(status) if status.success() => return Ok(()),
+        Ok(status) => format!("fetch-axe.sh exited with {}", status),
+        Err(e) => e.to_string(),
+    };
+    anyhow::bail!(
+        "axe-core not found at {} and could not be fetched ({}); run \
+         crates/e2e_tests/assets/fetch-axe.sh or point a11y.script at a local copy",
+        path,
+        reason
+    )
+}
+
+async fn inject_axe(driver: &WebDriver) -> WebDriverResult<Option<String>> {
+    let loaded: bool = driver
+        .execute("return !!window.axe;", Vec::new())
+        .await?
+        .convert()?;
+    if loaded {
+        return Ok(None);
+    }
+    let path = &get_config().a11y.script;
+    match fs::read_to_string(path) {
+        Ok(source) => {
+            driver.execute(&source, Vec::new()).await?;
+            Ok(None)
+        }
+        Err(e) => Ok(Some(format!(
+            "axe-core not found at {} ({}); run crates/e2e_tests/assets/fetch-axe.sh",
+            path, e
+        ))),
+    }
+}
+
+/// Runs axe-core against the current page and queues the result for the report; the
+/// enclosing `E2eSuite::step` records it. Violations never fail the flow itself.
+pub async fn audit(driver: &WebDriver, scope: Scope<'_>) -> WebDriverResult<A11yAudit> {
+    let cfg = &get_config().a11y;
+    let fail_on = Impact::parse(&cfg.fail_on).unwrap_or(Impact::Serious);
+    let mut audit = A11yAudit {
+        url: driver.current_url().await?.to_string(),
+        scope: scope.label(),
+        fail_on,
+        violations: Vec::new(),
+        error: None,
+    };
+
+    if let Some(reason) = inject_axe(driver).await? {
+        println!("❌ A11y audit could not run - {}", reason);
+        audit.error = Some(reason);
+    } else {
+        let raw: RawResults = driver
+            .execute(RUN_AXE, vec![scope.context(), axe_options()])
+            .await?
+            .convert()?;
+        audit.url = raw.url;
+        audit.violations = raw
+            .violations
+            .into_iter()
+            .flat_map(|v| {
+                v.targets.into_iter().map(move |selector| A11yViolation {
+                    rule: v.id.clone(),
+                    impact: v.impact.clone(),
+                    selector,
+                    help: v.help.clone(),
+                    help_url: v.help_url.clone(),
+                })
+            })
+            .collect();
+        println!(
+            "♿ {} ({}): {} violation(s), {} blocking",
+            page_name(&audit.url),
+            audit.scope,
+            audit.violations.len(),
+            audit.blocking().len()
+        );
+    }
+
+    if let Ok(mut audits) = AUDITS.lock() {
+        audits.push((driver.session_id().to_string(), audit.clone()));
+    }
+    Ok(audit)
+}
+
+/// Removes and returns the audits queued by `audit` for this browser session.
+pub fn take_audits(driver: &WebDriver) -> Vec<A11yAudit> {
+    let session = driver.session_id().to_string();
+    let Ok(mut audits) = AUDITS.lock() else {
+        return Vec::new();
+    };
+    let (mine, rest) = std::mem::take(&mut *audits)
+        .into_iter()
+        .partition(|(id, _)| *id == session);
+    *audits = rest;
+    mine.into_iter().map(|(_, audit)| audit).collect()
+}
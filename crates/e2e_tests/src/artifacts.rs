@@ -0,0 +1,207 @@
+use lib_test_helpers::reporting::Attachment;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use serde_json::{json, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+use thirtyfour::extensions::cdp::ChromeDevTools;
+use thirtyfour::prelude::*;
+
+// Buffers console output and uncaught errors in the page so they can be read back
+// after a failure; WebDriver has no portable console log endpoint.
+const CONSOLE_CAPTURE: &str = r#"
+if (!window.__qaConsole) {
+    window.__qaConsole = [];
+    const push = (level, args) => {
+        const text = Array.from(args).map(a => {
+            if (a instanceof Error) return a.stack || a.message;
+            if (typeof a === 'object') { try { return JSON.stringify(a); } catch (e) { return String(a); } }
+            return String(a);
+        }).join(' ');
+        window.__qaConsole.push({ level, text, time: new Date().toISOString() });
+        if (window.__qaConsole.length > 500) window.__qaConsole.shift();
+    };
+    ['log', 'info', 'warn', 'error', 'debug'].forEach(level => {
+        const original = console[level];
+        console[level] = function (...args) { push(level, args); return original.apply(this, args); };
+    });
+    window.addEventListener('error', e => push('uncaught', [e.message + ' at ' + e.filename + ':' + e.lineno]));
+    window.addEventListener('unhandledrejection', e => push('unhandledrejection', [e.reason]));
+}
+"#;
+
+pub async fn install_console_capture(driver: &WebDriver) -> WebDriverResult<()> {
+    let dev_tools = ChromeDevTools::new(driver.handle.clone());
+    // Chrome re-runs the hook on every navigation; other browsers only get the current page.
+    let _ = dev_tools
+        .execute_cdp_with_params(
+            "Page.addScriptToEvaluateOnNewDocument",
+            json!({ "source": CONSOLE_CAPTURE }),
+        )
+        .await;
+    driver.execute(CONSOLE_CAPTURE, Vec::new()).await?;
+    Ok(())
+}
+
+pub(crate) async fn full_page_screenshot(driver: &WebDriver) -> WebDriverResult<Vec<u8>> {
+    let dev_tools = ChromeDevTools::new(driver.handle.clone());
+    let metrics = dev_tools.execute_cdp("Page.getLayoutMetrics").await;
+    let size = metrics
+        .ok()
+        .and_then(|m| m.get("cssContentSize").or(m.get("contentSize")).cloned());
+
+    if let Some(size) = size {
+        let shot = dev_tools
+            .execute_cdp_with_params(
+                "Page.captureScreenshot",
+                json!({
+                    "format": "png",
+                    "captureBeyondViewport": true,
+                    "clip": {
+                        "x": 0,
+                        "y": 0,
+                        "width": size["width"],
+                        "height": size["height"],
+                        "scale": 1
+                    }
+                }),
+            )
+            .await;
+        if let Some(data) = shot.ok().and_then(|s| s["data"].as_str().map(String::from)) {
+            if let Ok(png) = STANDARD.decode(data) {
+                return Ok(png);
+            }
+        }
+    }
+
+    driver.screenshot_as_png().await
+}
+
+async fn console_log(driver: &WebDriver) -> WebDriverResult<String> {
+    let entries: Vec<Value> = driver
+        .execute("return window.__qaConsole || [];", Vec::new())
+        .await?
+        .convert()?;
+    if entries.is_empty() {
+        return Ok("(no console output captured)\n".to_string());
+    }
+    Ok(entries
+        .iter()
+        .map(|e| {
+            format!(
+                "{} [{}] {}\n",
+                e["time"].as_str().unwrap_or_default(),
+                e["level"].as_str().unwrap_or_default().to_uppercase(),
+                e["text"].as_str().unwrap_or_default()
+            )
+        })
+        .collect())
+}
+
+pub(crate) fn slug(name: &str) -> String {
+    let slug: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    slug.split('-')
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+fn save(
+    reports_dir: &Path,
+    dir: &Path,
+    file: &str,
+    label: &str,
+    contents: &[u8],
+    attachments: &mut Vec<Attachment>,
+) {
+    let path = dir.join(file);
+    match fs::write(&path, contents) {
+        Ok(()) => {
+            let relative = path.strip_prefix(reports_dir).unwrap_or(&path);
+            attachments.push(Attachment::new(
+                label,
+                relative.to_string_lossy().replace('\\', "/"),
+            ));
+        }
+        Err(e) => eprintln!("⚠️ Could not save {}: {}", path.display(), e),
+    }
+}
+
+/// Saves a screenshot, the page HTML, the console log and the URL for a failed step.
+/// Each artifact is best-effort so a dead browser still yields whatever it can.
+pub async fn capture_failure(
+    driver: &WebDriver,
+    reports_dir: &Path,
+    step_name: &str,
+) -> Vec<Attachment> {
+    let dir: PathBuf = reports_dir.join("artifacts").join(format!(
+        "{}-{}",
+        slug(step_name),
+        chrono::Local::now().format("%Y%m%d-%H%M%S")
+    ));
+    if let Err(e) = fs::create_dir_all(&dir) {
+        eprintln!("⚠️ Could not create {}: {}", dir.display(), e);
+        return Vec::new();
+    }
+
+    let mut attachments = Vec::new();
+
+    match full_page_screenshot(driver).await {
+        Ok(png) => save(
+            reports_dir,
+            &dir,
+            "screenshot.png",
+            "Screenshot",
+            &png,
+            &mut attachments,
+        ),
+        Err(e) => eprintln!("⚠️ Screenshot failed: {}", e),
+    }
+    match driver.source().await {
+        Ok(html) => save(
+            reports_dir,
+            &dir,
+            "page.html",
+            "Page HTML",
+            html.as_bytes(),
+            &mut attachments,
+        ),
+        Err(e) => eprintln!("⚠️ Page source failed: {}", e),
+    }
+    match console_log(driver).await {
+        Ok(log) => save(
+            reports_dir,
+            &dir,
+            "console.log",
+            "Console log",
+            log.as_bytes(),
+            &mut attachments,
+        ),
+        Err(e) => eprintln!("⚠️ Console log failed: {}", e),
+    }
+    let url = driver
+        .current_url()
+        .await
+        .map(|u| u.to_string())
+        .unwrap_or_else(|e| format!("(unavailable: {})", e));
+    save(
+        reports_dir,
+        &dir,
+        "url.txt",
+        "URL",
+        url.as_bytes(),
+        &mut attachments,
+    );
+
+    println!("📸 Failure artifacts saved to {}", dir.display());
+    attachments
+}
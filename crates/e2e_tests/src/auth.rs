@@ -0,0 +1,105 @@
+use anyhow::{bail, Context, Result};
+use api_tests::{ApiClient, AuthApi};
+use lib_test_helpers::config::{get_config, Persona};
+use lib_test_helpers::session_file::{check_logged_in, save_session, SessionKey};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use thirtyfour::prelude::*;
+use tokio::sync::OnceCell;
+
+const DEFAULT_TOKEN_KEY: &str = "access_token";
+
+type PersonaClient = OnceCell<&'static ApiClient>;
+
+/// Logged-in clients by persona email. They live for the whole run, so they are leaked.
+static PERSONA_CLIENTS: Mutex<BTreeMap<String, &'static PersonaClient>> =
+    Mutex::new(BTreeMap::new());
+
+/// Logs `persona` in through `AuthApi` (password + OTP) and hands the token to the browser,
+/// skipping the login screens entirely. The resulting session is cached like a UI login.
+pub async fn authenticate_browser(driver: &WebDriver, persona: &Persona) -> Result<()> {
+    let token = persona_client(persona)
+        .await?
+        .get_auth_token()
+        .await
+        .with_context(|| format!("No token after the API login for '{}'", persona.name))?;
+    inject_token(driver, persona, &token).await?;
+
+    if !check_logged_in(driver).await? {
+        bail!(
+            "Browser was not authenticated after injecting the API token for '{}'",
+            persona.name
+        );
+    }
+    save_session(driver, &SessionKey::of(persona)).await?;
+    println!("✅ Browser authenticated via API as '{}'", persona.name);
+    Ok(())
+}
+
+/// An API client logged in as `persona`, for tests that check the backend alongside the
+/// browser. Each persona logs in once per run on its own client, however many scenarios
+/// ask concurrently; `ApiClient::global()`, which the API suite uses, is left alone.
+pub async fn persona_client(persona: &Persona) -> Result<&'static ApiClient> {
+    let cell = *PERSONA_CLIENTS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .entry(persona.email.clone())
+        .or_insert_with(|| Box::leak(Box::new(OnceCell::new())));
+    cell.get_or_try_init(|| api_login(persona))
+        .await
+        .copied()
+        .with_context(|| format!("API login failed for persona '{}'", persona.name))
+}
+
+async fn api_login(persona: &Persona) -> Result<&'static ApiClient> {
+    let client: &'static ApiClient = Box::leak(Box::new(ApiClient::from_config()?));
+    let auth = AuthApi::with_client(client);
+    auth.login(persona.email.clone(), persona.password.clone())
+        .await?;
+    auth.verify_send_otp(
+        persona.mobile_number.clone(),
+        persona.country_code.clone(),
+        persona.email.clone(),
+    )
+    .await?;
+    auth.verify_otp(
+        persona.country_code.clone(),
+        persona.mobile_number.clone(),
+        persona.otp.clone(),
+        persona.email.clone(),
+    )
+    .await?;
+    Ok(client)
+}
+
+async fn inject_token(driver: &WebDriver, persona: &Persona, token: &str) -> WebDriverResult<()> {
+    let cfg = get_config();
+    let session = &cfg.session;
+    driver.goto(&cfg.base_url).await?;
+
+    let token_key = session.token_key.as_deref().unwrap_or(DEFAULT_TOKEN_KEY);
+    let mut entries = vec![(token_key.to_string(), token.to_string())];
+    entries.extend(session.inject_local_storage.iter().map(|(k, v)| {
+        let value = v
+            .replace("{token}", token)
+            .replace("{email}", &persona.email);
+        (k.clone(), value)
+    }));
+    for (key, value) in entries {
+        driver
+            .execute(
+                "window.localStorage.setItem(arguments[0], arguments[1]);",
+                vec![Value::String(key), Value::String(value)],
+            )
+            .await?;
+    }
+
+    if let Some(name) = &session.token_cookie {
+        let mut cookie = Cookie::new(name.clone(), token.to_string());
+        cookie.set_path("/");
+        driver.add_cookie(cookie).await?;
+    }
+
+    driver.refresh().await
+}
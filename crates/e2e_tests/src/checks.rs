@@ -0,0 +1,31 @@
+use crate::pages::{locators, ElderDetailsPage};
+use crate::suite::CheckOutcome;
+use crate::visual::VisualCheck;
+use lib_test_helpers::config::get_config;
+use std::path::PathBuf;
+use thirtyfour::prelude::*;
+
+pub async fn elder_details_layout(
+    driver: WebDriver,
+    base_url: String,
+    reports_dir: PathBuf,
+) -> WebDriverResult<CheckOutcome> {
+    let page = ElderDetailsPage::new(&driver);
+    page.open(&base_url, &get_config().elder_number).await?;
+    VisualCheck::page("elder-details")
+        .run(&driver, &reports_dir)
+        .await
+}
+
+pub async fn billing_drawer_layout(
+    driver: WebDriver,
+    base_url: String,
+    reports_dir: PathBuf,
+) -> WebDriverResult<CheckOutcome> {
+    let page = ElderDetailsPage::new(&driver);
+    page.open(&base_url, &get_config().elder_number).await?;
+    page.open_billing().await?;
+    VisualCheck::element("billing-drawer", locators::pos_billing::drawer())
+        .run(&driver, &reports_dir)
+        .await
+}
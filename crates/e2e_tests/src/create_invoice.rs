@@ -1,60 +1,55 @@
-use std::time::Duration;
-
-use lib_test_helpers::session_file::restore_session;
+use lib_test_helpers::india_data::IndiaDataFactory;
 use thirtyfour::prelude::*;
 
-use crate::utils::drop_down_select::{self, select_dropdown_option};
+use crate::a11y::{self, Scope};
+use crate::downloads::DownloadDir;
+use crate::pages::{ElderDetailsPage, PosBillingDrawer};
+use crate::pdf::{self, PdfText};
 
 pub async fn create_invoice(driver: WebDriver, base_url: String) -> WebDriverResult<()> {
-    let _ = restore_session(&driver, "session_daily", &base_url).await;
-    // driver.goto(&base_url).await?;
     let cfg = lib_test_helpers::config::get_config();
 
-    // select_dashboard(
-    //     &driver,
-    //     By::XPath("/html/body/div[1]/div[4]/div/div/aside/div/ul/li[6]/a"),
-    // )
-    // .await?;
-    driver
-        .goto(format!("{}elder/details/{}", base_url, cfg.elder_number))
-        .await?;
-
-    tokio::time::sleep(Duration::from_secs(15)).await;
-    driver
-        .query(By::XPath(
-            r#"//*[@id="app"]/div[5]/main/div[1]/div[2]/div/button[1]"#,
-        ))
-        .first()
-        .await?
-        .click()
-        .await?;
-
-    drop_down_select::select_dropdown_option(
-        &driver,
-        By::XPath("//*[@id=\"billing-center-select\"]"),
-        "Testing Node",
-    )
-    .await?;
-
-    driver
-        .find(By::XPath(
-            r#"//*[@id="app"]/div[5]/div/main/div[1]/div[1]/div[1]/div/button"#,
-        ))
-        .await?
-        .click()
-        .await?;
-    tokio::time::sleep(Duration::from_secs(2)).await;
-    driver
-        .find(By::Css("button.ant-btn.ant-btn-round.ant-btn-primary"))
-        .await?
-        .click()
-        .await?;
-    tokio::time::sleep(Duration::from_secs(2)).await;
-    select_dropdown_option(
-        &driver,
-        By::Css("div.ant-select-selector span.ant-select-selection-search"),
-        "HR,Haryana",
-    )
-    .await?;
-    Ok(())
+    let elder_page = ElderDetailsPage::new(&driver);
+    elder_page.open(&base_url, &cfg.elder_number).await?;
+    a11y::audit(&driver, Scope::Page).await?;
+
+    let billing = elder_page.open_billing().await?;
+    a11y::audit(&driver, Scope::Css(".ant-drawer-content-wrapper")).await?;
+    billing.select_billing_center("Testing Node").await?;
+    billing.add_items().await?;
+    billing.proceed().await?;
+
+    select_place_of_supply(&billing).await
+}
+
+async fn select_place_of_supply(billing: &PosBillingDrawer<'_>) -> WebDriverResult<()> {
+    let place_of_supply = IndiaDataFactory::state_by_code("HR")
+        .map(|state| state.label())
+        .unwrap_or_default();
+    billing.select_place_of_supply(&place_of_supply).await
+}
+
+/// Bills the elder, downloads the generated invoice and checks the PDF against the bill.
+pub async fn create_invoice_pdf(driver: WebDriver, base_url: String) -> WebDriverResult<()> {
+    let cfg = lib_test_helpers::config::get_config();
+    let downloads = DownloadDir::for_test(&driver, "invoice-pdf").await?;
+
+    let elder_page = ElderDetailsPage::new(&driver);
+    elder_page.open(&base_url, &cfg.elder_number).await?;
+    let customer_name = elder_page.elder_name().await?;
+
+    let billing = elder_page.open_billing().await?;
+    billing.select_billing_center("Testing Node").await?;
+    billing.add_items().await?;
+    let mut expected = billing.entered_invoice().await?;
+    billing.proceed().await?;
+    select_place_of_supply(&billing).await?;
+
+    expected.invoice_number = billing.generate_invoice().await?;
+    expected.customer_name = customer_name;
+    billing.download_invoice().await?;
+
+    let file = downloads.wait_for_download().await?;
+    println!("📄 Invoice downloaded to {}", file.display());
+    pdf::assert_invoice(&PdfText::read(&file)?, &expected)
 }
@@ -0,0 +1,119 @@
+use crate::auth::persona_client;
+use crate::create_lead::sample_lead;
+use crate::pages::{CreateLeadForm, LeadDetails, LeadsPage};
+use anyhow::{bail, Result};
+use api_tests::request_response::user_actions::lead::{LeadCreateRequest, LeadSummary};
+use api_tests::utils::request_factories::LeadCreateRequestFactory;
+use api_tests::{LeadCreateRequestApi, LeadSearchApi};
+use lib_test_helpers::config::Persona;
+use lib_test_helpers::wait::WaitPolicy;
+use thirtyfour::prelude::*;
+
+fn to_webdriver(e: anyhow::Error) -> WebDriverError {
+    WebDriverError::FatalError(format!("{:#}", e))
+}
+
+pub fn lead_request(lead: &LeadDetails) -> LeadCreateRequest {
+    LeadCreateRequestFactory::new()
+        .lead_name(lead.name.as_str())
+        .lead_phone(lead.mobile_number.as_str())
+        .lead_email(lead.email.as_str())
+        .lead_source_category(lead.source_category.as_str())
+        .lead_source(lead.source.as_str())
+        .campaign_name(lead.campaign_name.as_str())
+        .build()
+}
+
+pub async fn create_lead_via_api(lead: &LeadDetails) -> Result<String> {
+    let client = persona_client(&Persona::default_user()).await?;
+    let response = LeadCreateRequestApi::with_client(client)
+        .create_lead_request(lead_request(lead))
+        .await?;
+    Ok(response.data.createdLeadDetails.uuid)
+}
+
+pub async fn assert_lead_in_ui(
+    driver: &WebDriver,
+    base_url: &str,
+    lead: &LeadDetails,
+) -> WebDriverResult<()> {
+    let page = LeadsPage::new(driver);
+    page.open(base_url).await?;
+    let row = page.wait_for_lead(&lead.mobile_number).await?;
+    if !row.values().any(|cell| cell.contains(&lead.name)) {
+        return Err(WebDriverError::ParseError(format!(
+            "Lead {} is listed without the name '{}': {:?}",
+            lead.mobile_number, lead.name, row
+        )));
+    }
+    Ok(())
+}
+
+fn mismatches(lead: &LeadDetails, found: &LeadSummary) -> Vec<String> {
+    let mut diffs = Vec::new();
+    let mut check = |field: &str, expected: &str, actual: Option<&str>| match actual {
+        Some(actual) if !actual.trim().eq_ignore_ascii_case(expected.trim()) => diffs.push(
+            format!("{}: expected '{}', got '{}'", field, expected, actual),
+        ),
+        Some(_) => {}
+        None if expected.trim().is_empty() => {}
+        None => diffs.push(format!(
+            "{}: expected '{}', missing from the lead search",
+            field, expected
+        )),
+    };
+    check("name", &lead.name, Some(&found.name));
+    check("source", &lead.source, found.source.as_deref());
+    check(
+        "source_category",
+        &lead.source_category,
+        found.source_category.as_deref(),
+    );
+    check("email", &lead.email, found.email.as_deref());
+    diffs
+}
+
+/// Polls the lead search until the lead shows up, then compares what the backend stored
+/// against what was entered.
+pub async fn assert_lead_in_api(lead: &LeadDetails) -> Result<LeadSummary> {
+    let client = persona_client(&Persona::default_user()).await?;
+    let api = LeadSearchApi::with_client(client);
+    let found = WaitPolicy::global()
+        .for_page_load()
+        .until(
+            &format!("lead {} in the lead search", lead.mobile_number),
+            || async {
+                api.find_by_phone(&lead.mobile_number)
+                    .await
+                    .map_err(to_webdriver)
+            },
+        )
+        .await?;
+
+    let diffs = mismatches(lead, &found);
+    if !diffs.is_empty() {
+        bail!(
+            "Lead {} differs between UI and API: {}",
+            lead.mobile_number,
+            diffs.join("; ")
+        );
+    }
+    Ok(found)
+}
+
+pub async fn api_to_ui(driver: WebDriver, base_url: String) -> WebDriverResult<()> {
+    let lead = sample_lead();
+    let uuid = create_lead_via_api(&lead).await.map_err(to_webdriver)?;
+    println!("Created lead {} via API", uuid);
+    assert_lead_in_ui(&driver, &base_url, &lead).await
+}
+
+pub async fn ui_to_api(driver: WebDriver, _base_url: String) -> WebDriverResult<()> {
+    let lead = sample_lead();
+    let form = CreateLeadForm::new(&driver);
+    form.open().await?;
+    form.fill(&lead).await?;
+    form.submit().await?;
+    assert_lead_in_api(&lead).await.map_err(to_webdriver)?;
+    Ok(())
+}
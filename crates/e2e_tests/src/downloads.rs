@@ -0,0 +1,116 @@
+use crate::artifacts::slug;
+use lib_test_helpers::config::get_config;
+use lib_test_helpers::wait::WaitPolicy;
+use serde_json::json;
+use std::cell::Cell;
+use std::collections::HashSet;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use thirtyfour::extensions::cdp::ChromeDevTools;
+use thirtyfour::prelude::*;
+
+const DEFAULT_DOWNLOAD_DIR: &str = "crates/e2e_tests/reports/downloads";
+
+// Chrome, Firefox and Safari write to these while a download is in progress.
+const PARTIAL_SUFFIXES: [&str; 4] = [".crdownload", ".part", ".download", ".tmp"];
+
+fn is_partial(path: &Path) -> bool {
+    let name = path.to_string_lossy();
+    PARTIAL_SUFFIXES.iter().any(|suffix| name.ends_with(suffix))
+}
+
+fn list_files(dir: &Path) -> io::Result<HashSet<PathBuf>> {
+    let mut files = HashSet::new();
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        if entry.file_type()?.is_file() {
+            files.insert(entry.path());
+        }
+    }
+    Ok(files)
+}
+
+/// Where one test's downloads land. Files already there when it was created are ignored.
+pub struct DownloadDir {
+    path: PathBuf,
+    existing: HashSet<PathBuf>,
+}
+
+impl DownloadDir {
+    /// Chrome is pointed at a fresh directory for this test; other browsers can't change it
+    /// mid-session, so they keep `browser.download_dir` and only new files count.
+    pub async fn for_test(driver: &WebDriver, test_name: &str) -> WebDriverResult<Self> {
+        let base = PathBuf::from(
+            get_config()
+                .browser
+                .download_dir
+                .as_deref()
+                .unwrap_or(DEFAULT_DOWNLOAD_DIR),
+        );
+        let dir = base.join(format!(
+            "{}-{}",
+            slug(test_name),
+            chrono::Local::now().format("%Y%m%d-%H%M%S%3f")
+        ));
+        fs::create_dir_all(&dir)?;
+        let dir = fs::canonicalize(&dir)?;
+
+        let redirected = ChromeDevTools::new(driver.handle.clone())
+            .execute_cdp_with_params(
+                "Browser.setDownloadBehavior",
+                json!({ "behavior": "allow", "downloadPath": dir }),
+            )
+            .await;
+        let path = match redirected {
+            Ok(_) => dir,
+            Err(e) => {
+                let _ = fs::remove_dir(&dir);
+                let shared = fs::canonicalize(&base)?;
+                println!(
+                    "⚠️ {}: downloads go to the shared {} ({}); a file from a concurrent test could be picked up",
+                    test_name,
+                    shared.display(),
+                    e
+                );
+                shared
+            }
+        };
+
+        Ok(Self {
+            existing: list_files(&path)?,
+            path,
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Waits for a new file that is fully written: no partial download left and the same
+    /// size on two consecutive polls.
+    pub async fn wait_for_download(&self) -> WebDriverResult<PathBuf> {
+        let last_size = Cell::new(None);
+        WaitPolicy::global()
+            .for_page_load()
+            .until(
+                &format!("a download in {}", self.path.display()),
+                || async {
+                    let new: Vec<PathBuf> = list_files(&self.path)?
+                        .difference(&self.existing)
+                        .cloned()
+                        .collect();
+                    if new.iter().any(|path| is_partial(path)) {
+                        return Ok(None);
+                    }
+                    let Some(file) = new.into_iter().next() else {
+                        return Ok(None);
+                    };
+                    let size = fs::metadata(&file)?.len();
+                    let settled = size > 0 && last_size.replace(Some(size)) == Some(size);
+                    Ok(settled.then_some(file))
+                },
+            )
+            .await
+    }
+}
@@ -1,23 +1,146 @@
+pub mod a11y;
+pub mod artifacts;
+pub mod auth;
+pub mod checks;
 pub mod create_invoice;
 pub mod create_lead;
+pub mod cross_layer;
+pub mod downloads;
 pub mod login;
+pub mod network;
+pub mod pages;
+pub mod pdf;
+pub mod suite;
+pub mod visual;
 pub mod utils {
+    pub mod antd;
     pub mod dashboard_button;
     pub mod drop_down_select;
-    pub mod retry;
 }
 
-use anyhow::Result;
+use anyhow::{bail, Result};
+use futures::future::{join_all, LocalBoxFuture};
+use futures::FutureExt;
+use lib_test_helpers::browser_pool::{self, BrowserPool};
+use lib_test_helpers::config::Persona;
+use lib_test_helpers::reporting::{ConsoleReporter, TestReport};
+use lib_test_helpers::{seed, wait};
+use std::path::PathBuf;
+use suite::{CheckOutcome, E2eSuite};
+use thirtyfour::prelude::*;
+
+type Flow = fn(WebDriver, String) -> LocalBoxFuture<'static, WebDriverResult<()>>;
+type Check =
+    fn(WebDriver, String, PathBuf) -> LocalBoxFuture<'static, WebDriverResult<CheckOutcome>>;
+
+enum Scenario {
+    Flow(Flow),
+    Check(Check),
+}
+
+fn scenarios() -> Vec<(&'static str, Scenario)> {
+    vec![
+        // ("Create Lead", Scenario::Flow(|driver, base_url| create_lead::create_lead_test(driver, base_url).boxed_local())),
+        (
+            "Lead: API -> UI",
+            Scenario::Flow(|driver, base_url| {
+                cross_layer::api_to_ui(driver, base_url).boxed_local()
+            }),
+        ),
+        (
+            "Lead: UI -> API",
+            Scenario::Flow(|driver, base_url| {
+                cross_layer::ui_to_api(driver, base_url).boxed_local()
+            }),
+        ),
+        (
+            "Create Invoice",
+            Scenario::Flow(|driver, base_url| {
+                create_invoice::create_invoice(driver, base_url).boxed_local()
+            }),
+        ),
+        (
+            "Invoice PDF",
+            Scenario::Flow(|driver, base_url| {
+                create_invoice::create_invoice_pdf(driver, base_url).boxed_local()
+            }),
+        ),
+        (
+            "Visual: Elder details",
+            Scenario::Check(|driver, base_url, reports_dir| {
+                checks::elder_details_layout(driver, base_url, reports_dir).boxed_local()
+            }),
+        ),
+        (
+            "Visual: Billing drawer",
+            Scenario::Check(|driver, base_url, reports_dir| {
+                checks::billing_drawer_layout(driver, base_url, reports_dir).boxed_local()
+            }),
+        ),
+    ]
+}
 
 pub async fn run() -> Result<()> {
+    let report = run_suite().await?;
+    ConsoleReporter::new().print(&report);
+    suite::save_reports(&report)?;
+    if report.has_failures() {
+        bail!("{} E2E step(s) failed", report.failed);
+    }
+    Ok(())
+}
+
+/// Runs the E2E flows and page checks concurrently on a browser pool (`E2E_WORKERS` sessions) and returns
+/// the report; only browser start-up errors are returned as `Err`. Step failures, and
+/// scenarios that could not get a browser session, are recorded in the report.
+pub async fn run_suite() -> Result<TestReport> {
     let cfg = lib_test_helpers::config::get_config();
+    a11y::ensure_axe()?;
+    let scenarios = scenarios();
+    let pool = BrowserPool::builder(browser_pool::workers(scenarios.len()), &cfg.base_url)
+        .launch()
+        .await?;
+    let suite = E2eSuite::new();
+    let persona = Persona::default_user();
+
     // login::login_test(&cfg.base_url.as_str()).await?;
-    // tokio::time::sleep(Duration::from_millis(2000)).await;
-    // create_lead::create_lead_test(&cfg.base_url.as_str()).await?;
-    create_invoice::create_invoice(
-        lib_test_helpers::driver::global_driver().await?,
-        cfg.base_url.clone(),
-    )
-    .await?;
-    Ok(())
+    let runs = scenarios.into_iter().map(|(name, scenario)| {
+        let (pool, suite, persona) = (&pool, &suite, &persona);
+        let run = async move {
+            let lease = pool.lease().await?;
+            artifacts::install_console_capture(&lease).await?;
+            network::install_network_capture(&lease).await?;
+            wait::install_network_tracker(&lease).await?;
+            let driver = lease.driver().clone();
+            match scenario {
+                Scenario::Flow(flow) => {
+                    let _ = suite
+                        .step(&lease, name, || async move {
+                            login::ensure_logged_in(&driver, &cfg.base_url, persona).await?;
+                            flow(driver, cfg.base_url.clone()).await
+                        })
+                        .await;
+                }
+                Scenario::Check(check) => {
+                    let reports_dir = suite.reports_dir().to_path_buf();
+                    let _ = suite
+                        .check(&lease, name, || async move {
+                            login::ensure_logged_in(&driver, &cfg.base_url, persona).await?;
+                            check(driver, cfg.base_url.clone(), reports_dir).await
+                        })
+                        .await;
+                }
+            }
+            anyhow::Ok(())
+        };
+        seed::scoped(name, run).map(move |outcome| {
+            if let Err(e) = outcome {
+                suite.record_not_run(name, &e);
+            }
+        })
+    });
+    join_all(runs).await;
+    pool.shutdown().await;
+
+    Ok(suite.report())
 }
@@ -1,89 +1,50 @@
-use crate::utils::retry::retry;
-use lib_test_helpers::config::get_config;
-use lib_test_helpers::{driver::global_driver, session_file::save_session};
-use std::time::Duration;
+use crate::auth::authenticate_browser;
+use crate::pages::LoginPage;
+use lib_test_helpers::config::Persona;
+use lib_test_helpers::driver::global_driver;
+use lib_test_helpers::session_file::{check_logged_in, save_session, SessionKey};
 use thirtyfour::prelude::*;
-pub async fn login_test(base_url: &str) -> WebDriverResult<()> {
-    let driver = global_driver().await?;
-    driver.goto(base_url).await?;
-    let cfg = get_config();
-
-    driver
-        .find(By::Id("basic_email"))
-        .await?
-        .send_keys(&cfg.email)
-        .await?;
-    driver
-        .find(By::Id("basic_password"))
-        .await?
-        .send_keys(&cfg.password)
-        .await?;
-    driver
-        .find(By::Css("button[type='submit']"))
-        .await?
-        .click()
-        .await?;
 
-    retry(
-        || async {
-            driver
-                .query(By::XPath("//input[@placeholder='Enter Phone..']"))
-                .wait(Duration::from_secs(50), Duration::from_millis(500))
-                .first()
-                .await?
-                .send_keys(&cfg.mobile_number)
-                .await
-        },
-        5,
-        400,
-    )
-    .await?;
-
-    driver
-        .query(By::XPath("//input[@placeholder='Enter Phone..']"))
-        .wait(Duration::from_secs(50), Duration::from_millis(500))
-        .first()
-        .await?
-        .send_keys(&cfg.mobile_number)
-        .await?;
-    driver
-        .query(By::Css("button.otp-send-btn"))
-        .wait(Duration::from_secs(10), Duration::from_millis(500))
-        .first()
-        .await?
-        .click()
-        .await
-        .ok();
+/// Logs `persona` in through the UI and caches the session under that persona's key.
+pub async fn login(driver: &WebDriver, base_url: &str, persona: &Persona) -> WebDriverResult<()> {
+    let missing = persona.missing_credentials();
+    if !missing.is_empty() {
+        return Err(WebDriverError::FatalError(format!(
+            "Persona '{}' has no {} for a UI login",
+            persona.name,
+            missing.join(", ")
+        )));
+    }
+    LoginPage::new(driver).login(base_url, persona).await?;
+    if let Err(e) = save_session(driver, &SessionKey::of(persona)).await {
+        println!("⚠️ Could not cache session: {}", e);
+    }
+    Ok(())
+}
 
-    driver
-        .query(By::XPath(
-            "//input[@placeholder='Enter 4-digit OTP' and not(@disabled)]",
-        ))
-        .wait(Duration::from_secs(10), Duration::from_millis(500))
-        .first()
-        .await?
-        .send_keys(&cfg.otp)
-        .await?;
-    driver
-        .find(By::Css("button.enter-otp-verify-button-visible"))
-        .await?
-        .click()
-        .await?;
-    driver
-        .query(By::Css("button.No.thanks"))
-        .wait(Duration::from_secs(10), Duration::from_millis(500))
-        .first()
-        .await?
-        .click()
-        .await?;
+/// Keeps a restored session if the probe accepts it, otherwise logs in through the API and
+/// only falls back to the UI login when that fails.
+pub async fn ensure_logged_in(
+    driver: &WebDriver,
+    base_url: &str,
+    persona: &Persona,
+) -> WebDriverResult<()> {
+    if check_logged_in(driver).await.unwrap_or(false) {
+        return Ok(());
+    }
+    match authenticate_browser(driver, persona).await {
+        Ok(()) => Ok(()),
+        Err(e) => {
+            println!("⚠️ {:#}; logging in through the UI", e);
+            login(driver, base_url, persona).await
+        }
+    }
+}
 
-    driver
-        .query(By::XPath("//a[contains(., 'Create Lead')]"))
-        .wait(Duration::from_secs(10), Duration::from_millis(500))
-        .first()
-        .await?;
+pub async fn login_test(base_url: &str) -> WebDriverResult<()> {
+    let driver = global_driver().await?;
 
-    let _ = save_session(&driver, "session_daily").await;
+    login(&driver, base_url, &Persona::default_user()).await?;
 
     // create_lead::create_lead_test(&driver).await?;
 
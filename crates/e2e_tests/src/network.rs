@@ -0,0 +1,188 @@
+use lib_test_helpers::config::get_config;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use thirtyfour::extensions::cdp::ChromeDevTools;
+use thirtyfour::prelude::*;
+
+// Records every fetch/XHR the page makes plus LCP and CLS, which the Performance API only
+// reports to observers registered before they happen.
+const NETWORK_CAPTURE: &str = r#"
+if (!window.__qaNetCapture) {
+    const net = window.__qaNetCapture = { calls: [], lcp: null, cls: 0 };
+    const record = (call) => {
+        net.calls.push(call);
+        if (net.calls.length > 500) net.calls.shift();
+    };
+    const originalFetch = window.fetch;
+    window.fetch = function (input, init) {
+        const start = performance.now();
+        const method = String((init && init.method) || (input && input.method) || 'GET').toUpperCase();
+        const url = String((input && input.url) || input);
+        return originalFetch.apply(this, arguments).then(
+            res => { record({ kind: 'fetch', method, url: res.url || url, status: res.status, start, duration: performance.now() - start }); return res; },
+            err => { record({ kind: 'fetch', method, url, status: 0, start, duration: performance.now() - start, error: String(err) }); throw err; }
+        );
+    };
+    const open = XMLHttpRequest.prototype.open;
+    const send = XMLHttpRequest.prototype.send;
+    XMLHttpRequest.prototype.open = function (method, url) {
+        this.__qaCall = { method: String(method).toUpperCase(), url: String(url) };
+        return open.apply(this, arguments);
+    };
+    XMLHttpRequest.prototype.send = function () {
+        const call = this.__qaCall;
+        if (call) {
+            const start = performance.now();
+            this.addEventListener('loadend', () => record({
+                kind: 'xhr', method: call.method, url: this.responseURL || call.url, status: this.status,
+                start, duration: performance.now() - start,
+            }));
+        }
+        return send.apply(this, arguments);
+    };
+    try {
+        new PerformanceObserver(list => list.getEntries().forEach(e => { net.lcp = e.startTime; }))
+            .observe({ type: 'largest-contentful-paint', buffered: true });
+        new PerformanceObserver(list => list.getEntries().forEach(e => { if (!e.hadRecentInput) net.cls += e.value; }))
+            .observe({ type: 'layout-shift', buffered: true });
+    } catch (e) {}
+}
+"#;
+
+const TAKE_CAPTURE: &str = r#"
+const net = window.__qaNetCapture || { calls: [], lcp: null, cls: 0 };
+const nav = performance.getEntriesByType('navigation')[0];
+const fcp = performance.getEntriesByName('first-contentful-paint')[0];
+return {
+    calls: net.calls.splice(0),
+    timings: {
+        ttfb_ms: nav ? nav.responseStart : null,
+        dom_content_loaded_ms: nav ? nav.domContentLoadedEventEnd : null,
+        load_ms: nav && nav.loadEventEnd > 0 ? nav.loadEventEnd : null,
+        fcp_ms: fcp ? fcp.startTime : null,
+        lcp_ms: net.lcp,
+        cls: net.cls,
+    },
+};
+"#;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NetworkCall {
+    pub kind: String,
+    pub method: String,
+    pub url: String,
+    /// 0 when the request never got a response (CORS, offline, aborted).
+    pub status: u16,
+    pub start: f64,
+    pub duration: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl NetworkCall {
+    pub fn is_backend(&self) -> bool {
+        self.url
+            .starts_with(get_config().api_base_url.trim_end_matches('/'))
+    }
+
+    pub fn failed(&self) -> bool {
+        self.status == 0 || self.status >= 400
+    }
+
+    pub fn path(&self) -> &str {
+        self.url
+            .strip_prefix(get_config().api_base_url.trim_end_matches('/'))
+            .unwrap_or(&self.url)
+    }
+}
+
+/// Navigation and paint timings of the current document, in ms from navigation start.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PageTimings {
+    pub ttfb_ms: Option<f64>,
+    pub dom_content_loaded_ms: Option<f64>,
+    pub load_ms: Option<f64>,
+    pub fcp_ms: Option<f64>,
+    pub lcp_ms: Option<f64>,
+    #[serde(default)]
+    pub cls: f64,
+}
+
+/// Everything the page fetched since the previous `take`, plus its current timings.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NetworkCapture {
+    pub calls: Vec<NetworkCall>,
+    pub timings: PageTimings,
+}
+
+impl NetworkCapture {
+    pub fn failed_backend_calls(&self) -> Vec<&NetworkCall> {
+        self.calls
+            .iter()
+            .filter(|c| c.is_backend() && c.failed())
+            .collect()
+    }
+
+    /// e.g. `500 POST /api/v1/admin/invoice (312ms); 0 GET /api/v1/... (failed to fetch)`
+    pub fn failure_summary(&self) -> Option<String> {
+        let failed = self.failed_backend_calls();
+        if failed.is_empty() {
+            return None;
+        }
+        Some(
+            failed
+                .iter()
+                .map(|c| match &c.error {
+                    Some(error) => format!("{} {} {} ({})", c.status, c.method, c.path(), error),
+                    None => format!(
+                        "{} {} {} ({:.0}ms)",
+                        c.status,
+                        c.method,
+                        c.path(),
+                        c.duration
+                    ),
+                })
+                .collect::<Vec<_>>()
+                .join("; "),
+        )
+    }
+
+    pub fn metrics(&self) -> Vec<(String, f64)> {
+        let t = &self.timings;
+        let mut metrics = vec![
+            ("api_calls".to_string(), self.calls.len() as f64),
+            (
+                "api_failures".to_string(),
+                self.failed_backend_calls().len() as f64,
+            ),
+            ("cls".to_string(), t.cls),
+        ];
+        for (name, value) in [
+            ("ttfb_ms", t.ttfb_ms),
+            ("fcp_ms", t.fcp_ms),
+            ("lcp_ms", t.lcp_ms),
+        ] {
+            if let Some(value) = value {
+                metrics.push((name.to_string(), value));
+            }
+        }
+        metrics
+    }
+}
+
+pub async fn install_network_capture(driver: &WebDriver) -> WebDriverResult<()> {
+    let dev_tools = ChromeDevTools::new(driver.handle.clone());
+    let _ = dev_tools
+        .execute_cdp_with_params(
+            "Page.addScriptToEvaluateOnNewDocument",
+            json!({ "source": NETWORK_CAPTURE }),
+        )
+        .await;
+    driver.execute(NETWORK_CAPTURE, Vec::new()).await?;
+    Ok(())
+}
+
+/// Drains the calls recorded so far. Calls made before a full page load are lost.
+pub async fn take_capture(driver: &WebDriver) -> WebDriverResult<NetworkCapture> {
+    driver.execute(TAKE_CAPTURE, Vec::new()).await?.convert()
+}
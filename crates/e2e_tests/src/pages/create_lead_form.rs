@@ -0,0 +1,85 @@
+use super::{click, locators, type_into};
+use crate::utils::drop_down_select::select_dropdown_option;
+use thirtyfour::prelude::*;
+
+#[derive(Debug, Clone)]
+pub struct LeadDetails {
+    pub name: String,
+    pub mobile_number: String,
+    pub email: String,
+    pub source_category: String,
+    pub source: String,
+    pub campaign_name: String,
+    pub owner: String,
+}
+
+pub struct CreateLeadForm<'a> {
+    driver: &'a WebDriver,
+}
+
+impl<'a> CreateLeadForm<'a> {
+    pub fn new(driver: &'a WebDriver) -> Self {
+        Self { driver }
+    }
+
+    pub async fn open(&self) -> WebDriverResult<()> {
+        click(self.driver, locators::navigation::create_lead()).await
+    }
+
+    pub async fn enter_name(&self, name: &str) -> WebDriverResult<()> {
+        type_into(self.driver, locators::create_lead::lead_name(), name).await
+    }
+
+    pub async fn enter_mobile_number(&self, mobile_number: &str) -> WebDriverResult<()> {
+        type_into(
+            self.driver,
+            locators::create_lead::mobile_number(),
+            mobile_number,
+        )
+        .await
+    }
+
+    pub async fn enter_email(&self, email: &str) -> WebDriverResult<()> {
+        type_into(self.driver, locators::create_lead::email(), email).await
+    }
+
+    pub async fn select_source_category(&self, category: &str) -> WebDriverResult<()> {
+        select_dropdown_option(
+            self.driver,
+            locators::create_lead::source_category(),
+            category,
+        )
+        .await
+    }
+
+    pub async fn select_source(&self, source: &str) -> WebDriverResult<()> {
+        select_dropdown_option(self.driver, locators::create_lead::source(), source).await
+    }
+
+    pub async fn enter_campaign_name(&self, campaign_name: &str) -> WebDriverResult<()> {
+        type_into(
+            self.driver,
+            locators::create_lead::campaign_name(),
+            campaign_name,
+        )
+        .await
+    }
+
+    pub async fn select_owner(&self, owner: &str) -> WebDriverResult<()> {
+        select_dropdown_option(self.driver, locators::create_lead::owner(), owner).await
+    }
+
+    pub async fn fill(&self, lead: &LeadDetails) -> WebDriverResult<()> {
+        self.enter_name(&lead.name).await?;
+        self.enter_mobile_number(&lead.mobile_number).await?;
+        self.enter_email(&lead.email).await?;
+        self.select_source_category(&lead.source_category).await?;
+        self.select_source(&lead.source).await?;
+        self.enter_campaign_name(&lead.campaign_name).await?;
+        self.select_owner(&lead.owner).await
+    }
+
+    pub async fn submit(&self) -> WebDriverResult<()> {
+        click(self.driver, locators::create_lead::submit()).await
+    }
+}
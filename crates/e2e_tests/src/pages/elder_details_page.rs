@@ -0,0 +1,47 @@
+use super::{click, locators, wait_for, PosBillingDrawer};
+use lib_test_helpers::wait::{self, WaitPolicy};
+use thirtyfour::prelude::*;
+
+pub struct ElderDetailsPage<'a> {
+    driver: &'a WebDriver,
+}
+
+impl<'a> ElderDetailsPage<'a> {
+    pub fn new(driver: &'a WebDriver) -> Self {
+        Self { driver }
+    }
+
+    pub async fn open(&self, base_url: &str, elder_number: &str) -> WebDriverResult<()> {
+        self.driver
+            .goto(format!("{}elder/details/{}", base_url, elder_number))
+            .await?;
+        self.wait_until_loaded().await
+    }
+
+    pub async fn wait_until_loaded(&self) -> WebDriverResult<()> {
+        wait::wait_for_page_ready(self.driver).await?;
+        wait_for(
+            self.driver,
+            locators::elder_details::create_invoice(),
+            WaitPolicy::global().for_page_load(),
+        )
+        .await?;
+        Ok(())
+    }
+
+    pub async fn elder_name(&self) -> WebDriverResult<String> {
+        let heading = wait_for(
+            self.driver,
+            locators::elder_details::elder_name(),
+            WaitPolicy::global(),
+        )
+        .await?;
+        Ok(heading.text().await?.trim().to_string())
+    }
+
+    pub async fn open_billing(&self) -> WebDriverResult<PosBillingDrawer<'a>> {
+        click(self.driver, locators::elder_details::create_invoice()).await?;
+        wait::wait_for_network_idle(self.driver).await?;
+        Ok(PosBillingDrawer::new(self.driver))
+    }
+}
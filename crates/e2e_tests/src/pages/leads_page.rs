@@ -0,0 +1,55 @@
+use super::{locators, wait_for};
+use crate::utils::antd::table::AntTable;
+use lib_test_helpers::wait::{self, WaitPolicy};
+use std::collections::BTreeMap;
+use thirtyfour::prelude::*;
+
+pub struct LeadsPage<'a> {
+    driver: &'a WebDriver,
+}
+
+impl<'a> LeadsPage<'a> {
+    pub fn new(driver: &'a WebDriver) -> Self {
+        Self { driver }
+    }
+
+    pub async fn open(&self, base_url: &str) -> WebDriverResult<()> {
+        self.driver.goto(format!("{}leads", base_url)).await?;
+        wait::wait_for_page_ready(self.driver).await?;
+        wait_for(
+            self.driver,
+            locators::leads::table(),
+            WaitPolicy::global().for_page_load(),
+        )
+        .await?;
+        Ok(())
+    }
+
+    pub async fn search(&self, query: &str) -> WebDriverResult<()> {
+        let input = wait_for(self.driver, locators::leads::search(), WaitPolicy::global()).await?;
+        input.clear().await?;
+        input.send_keys(query).await?;
+        input.send_keys(Key::Enter).await?;
+        wait::wait_for_network_idle(self.driver).await?;
+        self.table().wait_loaded().await
+    }
+
+    pub fn table(&self) -> AntTable<'a> {
+        AntTable::new(self.driver, locators::leads::table())
+    }
+
+    /// Searches for the phone number until a matching row shows up; new leads can take a
+    /// moment to be indexed for search.
+    pub async fn wait_for_lead(&self, phone: &str) -> WebDriverResult<BTreeMap<String, String>> {
+        WaitPolicy::global()
+            .for_page_load()
+            .until(&format!("lead {} in the leads table", phone), || async {
+                self.search(phone).await?;
+                let rows = self.table().rows_as_maps().await?;
+                Ok(rows
+                    .into_iter()
+                    .find(|row| row.values().any(|cell| cell.contains(phone))))
+            })
+            .await
+    }
+}
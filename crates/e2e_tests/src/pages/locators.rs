@@ -0,0 +1,153 @@
+use thirtyfour::By;
+
+pub mod login {
+    use super::By;
+
+    pub fn email() -> By {
+        By::Id("basic_email")
+    }
+
+    pub fn password() -> By {
+        By::Id("basic_password")
+    }
+
+    pub fn submit() -> By {
+        By::Css("button[type='submit']")
+    }
+
+    pub fn phone() -> By {
+        By::XPath("//input[@placeholder='Enter Phone..']")
+    }
+
+    pub fn send_otp() -> By {
+        By::Css("button.otp-send-btn")
+    }
+
+    pub fn otp() -> By {
+        By::XPath("//input[@placeholder='Enter 4-digit OTP' and not(@disabled)]")
+    }
+
+    pub fn verify_otp() -> By {
+        By::Css("button.enter-otp-verify-button-visible")
+    }
+
+    pub fn dismiss_prompt() -> By {
+        By::Css("button.No.thanks")
+    }
+}
+
+pub mod navigation {
+    use super::By;
+
+    pub fn create_lead() -> By {
+        By::XPath("//a[contains(., 'Create Lead')]")
+    }
+}
+
+pub mod create_lead {
+    use super::By;
+
+    pub fn lead_name() -> By {
+        By::XPath("//input[@placeholder='Lead Name']")
+    }
+
+    pub fn mobile_number() -> By {
+        By::XPath("//input[@placeholder='Lead Mobile Number']")
+    }
+
+    pub fn email() -> By {
+        By::XPath("//input[@placeholder='Email Address']")
+    }
+
+    pub fn source_category() -> By {
+        By::Id("basic_lead_source_category")
+    }
+
+    pub fn source() -> By {
+        By::Id("basic_lead_source")
+    }
+
+    pub fn campaign_name() -> By {
+        By::XPath("//input[@placeholder='Campaign Name']")
+    }
+
+    pub fn owner() -> By {
+        By::Id("basic_lead_owner_uuid")
+    }
+
+    pub fn submit() -> By {
+        By::Css("button[type='submit']")
+    }
+}
+
+pub mod elder_details {
+    use super::By;
+
+    pub fn create_invoice() -> By {
+        By::XPath(r#"//*[@id="app"]/div[5]/main/div[1]/div[2]/div/button[1]"#)
+    }
+
+    pub fn elder_name() -> By {
+        By::XPath(r#"//*[@id="app"]/div[5]/main//h2[1]"#)
+    }
+}
+
+pub mod pos_billing {
+    use super::By;
+
+    pub fn drawer() -> By {
+        By::Css(".ant-drawer-content-wrapper")
+    }
+
+    pub fn billing_center() -> By {
+        By::XPath(r#"//*[@id="billing-center-select"]"#)
+    }
+
+    pub fn add_items() -> By {
+        By::XPath(r#"//*[@id="app"]/div[5]/div/main/div[1]/div[1]/div[1]/div/button"#)
+    }
+
+    pub fn proceed() -> By {
+        By::Css("button.ant-btn.ant-btn-round.ant-btn-primary")
+    }
+
+    pub fn place_of_supply() -> By {
+        By::Css("div.ant-select-selector span.ant-select-selection-search")
+    }
+
+    pub fn cart_table() -> By {
+        By::Css(".ant-drawer-content-wrapper .ant-table-wrapper")
+    }
+
+    /// The value shown next to a bill summary label such as "GST" or "Total".
+    pub fn summary_value(label: &str) -> By {
+        By::XPath(format!(
+            "//div[contains(@class, 'ant-drawer-content')]//*[starts-with(normalize-space(text()), '{}')]/following-sibling::*[1]",
+            label
+        ))
+    }
+
+    pub fn generate_invoice() -> By {
+        By::XPath("//div[contains(@class, 'ant-drawer-content')]//button[contains(., 'Generate Invoice')]")
+    }
+
+    pub fn invoice_number() -> By {
+        By::XPath("//div[contains(@class, 'ant-drawer-content')]//*[contains(normalize-space(text()), 'Invoice No')]/following-sibling::*[1]")
+    }
+
+    pub fn download_invoice() -> By {
+        By::XPath("//div[contains(@class, 'ant-drawer-content')]//*[self::button or self::a][contains(., 'Download')]")
+    }
+}
+
+pub mod leads {
+    use super::By;
+
+    pub fn search() -> By {
+        By::XPath("//input[contains(@placeholder, 'Search')]")
+    }
+
+    pub fn table() -> By {
+        By::Css(".ant-table-wrapper")
+    }
+}
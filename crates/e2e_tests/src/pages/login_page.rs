@@ -0,0 +1,72 @@
+use super::{click, locators, type_into, wait_for};
+use lib_test_helpers::config::Persona;
+use lib_test_helpers::wait::{self, WaitPolicy};
+use thirtyfour::prelude::*;
+
+pub struct LoginPage<'a> {
+    driver: &'a WebDriver,
+}
+
+impl<'a> LoginPage<'a> {
+    pub fn new(driver: &'a WebDriver) -> Self {
+        Self { driver }
+    }
+
+    pub async fn open(&self, base_url: &str) -> WebDriverResult<()> {
+        self.driver.goto(base_url).await
+    }
+
+    pub async fn submit_credentials(&self, email: &str, password: &str) -> WebDriverResult<()> {
+        type_into(self.driver, locators::login::email(), email).await?;
+        type_into(self.driver, locators::login::password(), password).await?;
+        click(self.driver, locators::login::submit()).await
+    }
+
+    pub async fn enter_phone(&self, mobile_number: &str) -> WebDriverResult<()> {
+        wait::wait_for_page_ready(self.driver).await?;
+        let phone = wait_for(
+            self.driver,
+            locators::login::phone(),
+            WaitPolicy::global().for_page_load(),
+        )
+        .await?;
+        wait::wait_for_stable(&phone).await?;
+        phone.send_keys(mobile_number).await
+    }
+
+    pub async fn request_otp(&self) -> WebDriverResult<()> {
+        // The button is skipped when the OTP is sent automatically.
+        click(self.driver, locators::login::send_otp()).await.ok();
+        Ok(())
+    }
+
+    pub async fn verify_otp(&self, otp: &str) -> WebDriverResult<()> {
+        type_into(self.driver, locators::login::otp(), otp).await?;
+        click(self.driver, locators::login::verify_otp()).await
+    }
+
+    pub async fn dismiss_prompt(&self) -> WebDriverResult<()> {
+        click(self.driver, locators::login::dismiss_prompt()).await
+    }
+
+    pub async fn wait_for_dashboard(&self) -> WebDriverResult<()> {
+        wait_for(
+            self.driver,
+            locators::navigation::create_lead(),
+            WaitPolicy::global().for_page_load(),
+        )
+        .await?;
+        Ok(())
+    }
+
+    pub async fn login(&self, base_url: &str, persona: &Persona) -> WebDriverResult<()> {
+        self.open(base_url).await?;
+        self.submit_credentials(&persona.email, &persona.password)
+            .await?;
+        self.enter_phone(&persona.mobile_number).await?;
+        self.request_otp().await?;
+        self.verify_otp(&persona.otp).await?;
+        self.dismiss_prompt().await?;
+        self.wait_for_dashboard().await
+    }
+}
@@ -0,0 +1,39 @@
+pub mod create_lead_form;
+pub mod elder_details_page;
+pub mod leads_page;
+pub mod locators;
+pub mod login_page;
+pub mod pos_billing_drawer;
+
+pub use create_lead_form::{CreateLeadForm, LeadDetails};
+pub use elder_details_page::ElderDetailsPage;
+pub use leads_page::LeadsPage;
+pub use login_page::LoginPage;
+pub use pos_billing_drawer::PosBillingDrawer;
+
+use lib_test_helpers::wait::{self, WaitPolicy};
+use thirtyfour::prelude::*;
+
+pub(crate) async fn wait_for(
+    driver: &WebDriver,
+    locator: By,
+    policy: WaitPolicy,
+) -> WebDriverResult<WebElement> {
+    driver
+        .query(locator)
+        .wait(policy.timeout, policy.poll_interval)
+        .first()
+        .await
+}
+
+pub(crate) async fn click(driver: &WebDriver, locator: By) -> WebDriverResult<()> {
+    let element = wait::wait_for_clickable(driver, locator).await?;
+    element.scroll_into_view().await?;
+    element.click().await
+}
+
+pub(crate) async fn type_into(driver: &WebDriver, locator: By, text: &str) -> WebDriverResult<()> {
+    let element = wait_for(driver, locator, WaitPolicy::global()).await?;
+    wait::wait_for_stable(&element).await?;
+    element.send_keys(text).await
+}
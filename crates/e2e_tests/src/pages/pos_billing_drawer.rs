@@ -0,0 +1,123 @@
+use super::{click, locators, wait_for};
+use crate::pdf::{amounts_in, ExpectedInvoice, InvoiceLine};
+use crate::utils::antd::table::AntTable;
+use crate::utils::drop_down_select::select_dropdown_option;
+use lib_test_helpers::wait::{self, WaitPolicy};
+use std::collections::BTreeMap;
+use thirtyfour::prelude::*;
+
+fn column(row: &BTreeMap<String, String>, names: &[&str]) -> Option<String> {
+    names.iter().find_map(|name| {
+        row.iter()
+            .find(|(header, _)| header.trim().eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.trim().to_string())
+    })
+}
+
+pub struct PosBillingDrawer<'a> {
+    driver: &'a WebDriver,
+}
+
+impl<'a> PosBillingDrawer<'a> {
+    pub fn new(driver: &'a WebDriver) -> Self {
+        Self { driver }
+    }
+
+    async fn wait_until_settled(&self) -> WebDriverResult<()> {
+        wait::wait_for_network_idle(self.driver).await?;
+        wait::wait_for_spinners(self.driver).await
+    }
+
+    pub async fn select_billing_center(&self, name: &str) -> WebDriverResult<()> {
+        select_dropdown_option(self.driver, locators::pos_billing::billing_center(), name).await
+    }
+
+    pub async fn add_items(&self) -> WebDriverResult<()> {
+        click(self.driver, locators::pos_billing::add_items()).await?;
+        self.wait_until_settled().await
+    }
+
+    pub async fn proceed(&self) -> WebDriverResult<()> {
+        click(self.driver, locators::pos_billing::proceed()).await?;
+        self.wait_until_settled().await
+    }
+
+    pub async fn select_place_of_supply(&self, state_label: &str) -> WebDriverResult<()> {
+        select_dropdown_option(
+            self.driver,
+            locators::pos_billing::place_of_supply(),
+            state_label,
+        )
+        .await
+    }
+
+    async fn summary_amount(&self, label: &str, element: WebElement) -> WebDriverResult<f64> {
+        let text = element.text().await?;
+        amounts_in(&text)
+            .first()
+            .copied()
+            .ok_or_else(|| WebDriverError::ParseError(format!("No amount in {} '{}'", label, text)))
+    }
+
+    /// The cart and bill summary as the drawer shows them, to check the invoice against.
+    /// Invoice number and customer name are left for the caller.
+    pub async fn entered_invoice(&self) -> WebDriverResult<ExpectedInvoice> {
+        let rows = AntTable::new(self.driver, locators::pos_billing::cart_table())
+            .rows_as_maps()
+            .await?;
+        let items = rows
+            .iter()
+            .map(|row| InvoiceLine {
+                name: column(row, &["Item", "Item Name", "Name", "Product"]).unwrap_or_default(),
+                quantity: column(row, &["Qty", "Quantity"]).and_then(|q| q.parse().ok()),
+                amount: column(row, &["Amount", "Net Amount", "Total"])
+                    .and_then(|a| amounts_in(&a).first().copied())
+                    .unwrap_or_default(),
+            })
+            .collect();
+
+        let total = wait_for(
+            self.driver,
+            locators::pos_billing::summary_value("Total"),
+            WaitPolicy::global(),
+        )
+        .await?;
+        let gst = match self
+            .driver
+            .query(locators::pos_billing::summary_value("GST"))
+            .nowait()
+            .first()
+            .await
+        {
+            Ok(element) => Some(self.summary_amount("GST", element).await?),
+            Err(_) => None,
+        };
+
+        Ok(ExpectedInvoice {
+            items,
+            gst,
+            total: self.summary_amount("Total", total).await?,
+            ..ExpectedInvoice::default()
+        })
+    }
+
+    /// Returns the invoice number if the drawer shows one once the invoice is generated.
+    pub async fn generate_invoice(&self) -> WebDriverResult<Option<String>> {
+        click(self.driver, locators::pos_billing::generate_invoice()).await?;
+        self.wait_until_settled().await?;
+        match self
+            .driver
+            .query(locators::pos_billing::invoice_number())
+            .nowait()
+            .first()
+            .await
+        {
+            Ok(element) => Ok(Some(element.text().await?.trim().to_string())),
+            Err(_) => Ok(None),
+        }
+    }
+
+    pub async fn download_invoice(&self) -> WebDriverResult<()> {
+        click(self.driver, locators::pos_billing::download_invoice()).await
+    }
+}
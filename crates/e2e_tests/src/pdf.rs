@@ -0,0 +1,169 @@
+use std::path::Path;
+use thirtyfour::prelude::*;
+
+const AMOUNT_TOLERANCE: f64 = 0.01;
+
+/// Every number in `text`, ignoring currency symbols and thousands separators:
+/// `"₹1,234.50 x 2"` gives `[1234.5, 2.0]`.
+pub fn amounts_in(text: &str) -> Vec<f64> {
+    text.split(|c: char| !(c.is_ascii_digit() || c == ',' || c == '.'))
+        .map(|token| {
+            token
+                .trim_matches(|c| c == ',' || c == '.')
+                .replace(',', "")
+        })
+        .filter(|token| token.chars().any(|c| c.is_ascii_digit()))
+        .filter_map(|token| token.parse().ok())
+        .collect()
+}
+
+fn same_amount(a: f64, b: f64) -> bool {
+    (a - b).abs() <= AMOUNT_TOLERANCE
+}
+
+fn normalize(text: &str) -> String {
+    text.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// Text extracted from a PDF, one string per page.
+#[derive(Debug, Clone)]
+pub struct PdfText {
+    pub pages: Vec<String>,
+}
+
+impl PdfText {
+    #[allow(clippy::result_large_err)]
+    pub fn read(path: &Path) -> WebDriverResult<Self> {
+        let pages = pdf_extract::extract_text_by_pages(path).map_err(|e| {
+            WebDriverError::ParseError(format!("Could not read PDF {}: {}", path.display(), e))
+        })?;
+        Ok(Self { pages })
+    }
+
+    pub fn text(&self) -> String {
+        self.pages.join("\n")
+    }
+
+    pub fn lines(&self) -> Vec<&str> {
+        self.pages
+            .iter()
+            .flat_map(|page| page.lines())
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect()
+    }
+
+    /// Case- and whitespace-insensitive, since PDF text extraction rarely keeps spacing.
+    pub fn contains(&self, needle: &str) -> bool {
+        normalize(&self.text()).contains(&normalize(needle))
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct InvoiceLine {
+    pub name: String,
+    pub quantity: Option<u32>,
+    pub amount: f64,
+}
+
+/// What the test entered in the billing flow, to compare against the generated invoice.
+#[derive(Debug, Clone, Default)]
+pub struct ExpectedInvoice {
+    pub invoice_number: Option<String>,
+    pub customer_name: String,
+    pub items: Vec<InvoiceLine>,
+    pub gst: Option<f64>,
+    pub total: f64,
+}
+
+/// Every way the PDF disagrees with `expected`. Line items match on the line naming the item
+/// plus the two after it, as table cells often wrap.
+pub fn invoice_mismatches(pdf: &PdfText, expected: &ExpectedInvoice) -> Vec<String> {
+    let lines = pdf.lines();
+    let mut diffs = Vec::new();
+
+    if let Some(number) = &expected.invoice_number {
+        if !pdf.contains(number) {
+            diffs.push(format!("invoice number '{}' not found", number));
+        }
+    }
+    if !expected.customer_name.is_empty() && !pdf.contains(&expected.customer_name) {
+        diffs.push(format!(
+            "customer name '{}' not found",
+            expected.customer_name
+        ));
+    }
+
+    for item in &expected.items {
+        let name = normalize(&item.name);
+        let Some(start) = lines.iter().position(|l| normalize(l).contains(&name)) else {
+            diffs.push(format!("line item '{}' not found", item.name));
+            continue;
+        };
+        let amounts: Vec<f64> = lines[start..(start + 3).min(lines.len())]
+            .iter()
+            .flat_map(|l| amounts_in(l))
+            .collect();
+        if !amounts.iter().any(|a| same_amount(*a, item.amount)) {
+            diffs.push(format!(
+                "line item '{}': amount {:.2} not found in {:?}",
+                item.name, item.amount, amounts
+            ));
+        }
+        if let Some(quantity) = item.quantity {
+            if !amounts.iter().any(|a| same_amount(*a, quantity as f64)) {
+                diffs.push(format!(
+                    "line item '{}': quantity {} not found in {:?}",
+                    item.name, quantity, amounts
+                ));
+            }
+        }
+    }
+
+    if let Some(gst) = expected.gst {
+        // Intra-state invoices split GST into CGST + SGST halves.
+        let gst_amounts: Vec<f64> = lines
+            .iter()
+            .filter(|l| l.to_uppercase().contains("GST"))
+            .flat_map(|l| amounts_in(l))
+            .collect();
+        let found = gst_amounts.iter().enumerate().any(|(i, a)| {
+            same_amount(*a, gst) || gst_amounts[i + 1..].iter().any(|b| same_amount(a + b, gst))
+        });
+        if !found {
+            diffs.push(format!(
+                "GST {:.2} not found on GST lines {:?}",
+                gst, gst_amounts
+            ));
+        }
+    }
+
+    let totals: Vec<f64> = lines
+        .iter()
+        .filter(|l| l.to_lowercase().contains("total"))
+        .flat_map(|l| amounts_in(l))
+        .collect();
+    if !totals.iter().any(|a| same_amount(*a, expected.total)) {
+        diffs.push(format!(
+            "bill total {:.2} not found on total lines {:?}",
+            expected.total, totals
+        ));
+    }
+
+    diffs
+}
+
+#[allow(clippy::result_large_err)]
+pub fn assert_invoice(pdf: &PdfText, expected: &ExpectedInvoice) -> WebDriverResult<()> {
+    let diffs = invoice_mismatches(pdf, expected);
+    if diffs.is_empty() {
+        return Ok(());
+    }
+    Err(WebDriverError::ParseError(format!(
+        "Invoice PDF does not match the bill:\n  {}",
+        diffs.join("\n  ")
+    )))
+}
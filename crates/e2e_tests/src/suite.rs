@@ -0,0 +1,232 @@
+use crate::network::{self, NetworkCapture};
+use crate::{a11y, artifacts};
+use anyhow::Result;
+use lib_test_helpers::reporting::{self, Attachment, TestCollector, TestReport, TestResult};
+use std::collections::BTreeMap;
+use std::fs;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+use thirtyfour::prelude::*;
+
+const REPORTS_DIR: &str = "crates/e2e_tests/reports";
+
+/// Result of a step that inspects the page rather than drives it (visual diff, a11y scan…):
+/// the step itself worked, but the page may still fail the check.
+#[derive(Debug, Clone, Default)]
+pub struct CheckOutcome {
+    pub failure: Option<String>,
+    pub attachments: Vec<Attachment>,
+    pub metrics: Vec<(String, f64)>,
+}
+
+impl CheckOutcome {
+    pub fn passed() -> Self {
+        Self::default()
+    }
+
+    pub fn failed(message: impl Into<String>) -> Self {
+        Self {
+            failure: Some(message.into()),
+            ..Self::default()
+        }
+    }
+
+    pub fn with_attachment(mut self, attachment: Attachment) -> Self {
+        self.attachments.push(attachment);
+        self
+    }
+
+    pub fn with_metric(mut self, name: impl Into<String>, value: f64) -> Self {
+        self.metrics.push((name.into(), value));
+        self
+    }
+}
+
+pub struct E2eSuite {
+    collector: TestCollector,
+    reports_dir: PathBuf,
+}
+
+impl E2eSuite {
+    pub fn new() -> Self {
+        let collector = TestCollector::new();
+        collector.set_suite_name("E2E Test Suite");
+        collector.start();
+        Self {
+            collector,
+            reports_dir: PathBuf::from(REPORTS_DIR),
+        }
+    }
+
+    pub fn reports_dir(&self) -> &Path {
+        &self.reports_dir
+    }
+
+    pub async fn step<F, Fut, T>(
+        &self,
+        driver: &WebDriver,
+        name: &str,
+        action: F,
+    ) -> WebDriverResult<T>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = WebDriverResult<T>>,
+    {
+        self.record(driver, name, action(), |_| CheckOutcome::passed())
+            .await
+    }
+
+    /// Like `step`, but a check that runs cleanly can still fail, and its attachments and
+    /// metrics are recorded whether it passes or not.
+    pub async fn check<F, Fut>(
+        &self,
+        driver: &WebDriver,
+        name: &str,
+        action: F,
+    ) -> WebDriverResult<CheckOutcome>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = WebDriverResult<CheckOutcome>>,
+    {
+        self.record(driver, name, action(), CheckOutcome::clone)
+            .await
+    }
+
+    /// Runs `action` and records it with the backend calls it made; `judge` says whether a
+    /// value it returned still fails the step.
+    async fn record<Fut, T>(
+        &self,
+        driver: &WebDriver,
+        name: &str,
+        action: Fut,
+        judge: impl FnOnce(&T) -> CheckOutcome,
+    ) -> WebDriverResult<T>
+    where
+        Fut: Future<Output = WebDriverResult<T>>,
+    {
+        println!("▶ {}", name);
+        let _ = network::take_capture(driver).await;
+        let start = Instant::now();
+        let outcome = action.await;
+        let duration_ms = start.elapsed().as_millis() as u64;
+        let capture = network::take_capture(driver).await.unwrap_or_default();
+        let url = driver
+            .current_url()
+            .await
+            .map(|u| u.to_string())
+            .unwrap_or_default();
+
+        let result = match &outcome {
+            Ok(value) => {
+                let check = judge(value);
+                let result = match &check.failure {
+                    None => {
+                        println!("{} successful", name);
+                        TestResult::ui_passed(name, &url, duration_ms)
+                    }
+                    Some(failure) => {
+                        println!("{} failed - {}", name, failure);
+                        TestResult::ui_failed(name, &url, duration_ms, failure)
+                    }
+                };
+                check
+                    .metrics
+                    .into_iter()
+                    .fold(result, |r, (k, v)| r.with_metric(k, v))
+                    .with_attachments(check.attachments)
+            }
+            Err(e) => {
+                println!("{} failed - {}", name, e);
+                std::fs::create_dir_all(&self.reports_dir).ok();
+                let attachments = artifacts::capture_failure(driver, &self.reports_dir, name).await;
+                TestResult::ui_failed(name, &url, duration_ms, e.to_string())
+                    .with_attachments(attachments)
+            }
+        };
+        let result = self.with_network(result, &capture, name);
+        self.collector.record(result);
+        self.record_audits(driver);
+
+        outcome
+    }
+
+    /// Adds timings and call counts as metrics, and flags backend calls that failed while
+    /// the step ran: in the error when the step failed, as a parameter otherwise.
+    fn with_network(&self, result: TestResult, capture: &NetworkCapture, name: &str) -> TestResult {
+        let mut result = capture
+            .metrics()
+            .into_iter()
+            .fold(result, |r, (k, v)| r.with_metric(k, v));
+        let Some(summary) = capture.failure_summary() else {
+            return result;
+        };
+        println!("⚠️ {} - backend errors: {}", name, summary);
+
+        let dir = self.reports_dir.join("network");
+        let path = dir.join(format!(
+            "{}-{}.json",
+            artifacts::slug(name),
+            chrono::Local::now().format("%Y%m%d-%H%M%S")
+        ));
+        let saved = fs::create_dir_all(&dir)
+            .and_then(|_| fs::write(&path, serde_json::to_string_pretty(capture)?));
+        match saved {
+            Ok(()) => {
+                let relative = path.strip_prefix(&self.reports_dir).unwrap_or(&path);
+                result.attachments.push(Attachment::new(
+                    "Network log",
+                    relative.to_string_lossy().replace('\\', "/"),
+                ));
+            }
+            Err(e) => eprintln!("⚠️ Could not save {}: {}", path.display(), e),
+        }
+
+        match result.error_message.take() {
+            Some(error) => {
+                result.error_message = Some(format!(
+                    "{}\nBackend errors seen by the UI: {}",
+                    error, summary
+                ));
+            }
+            None => {
+                result = result
+                    .with_parameters(BTreeMap::from([("backend_errors".to_string(), summary)]));
+            }
+        }
+        result
+    }
+
+    /// Records a scenario that never got to run, e.g. because no browser session could be
+    /// leased for it.
+    pub fn record_not_run(&self, name: &str, error: &anyhow::Error) {
+        println!("{} failed - could not start: {:#}", name, error);
+        self.collector.record(TestResult::ui_failed(
+            name,
+            "",
+            0,
+            format!("Scenario could not start: {:#}", error),
+        ));
+    }
+
+    /// Records the accessibility audits the step ran, one result per audit.
+    fn record_audits(&self, driver: &WebDriver) {
+        for audit in a11y::take_audits(driver) {
+            self.collector.record(audit.to_result());
+        }
+    }
+
+    pub fn report(&self) -> TestReport {
+        self.collector.generate_report()
+    }
+}
+
+impl Default for E2eSuite {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub fn save_reports(report: &TestReport) -> Result<()> {
+    reporting::save_all(report, Path::new(REPORTS_DIR), "e2e_report")
+}
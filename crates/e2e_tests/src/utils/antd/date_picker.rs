@@ -0,0 +1,147 @@
+use super::{not_found, visible};
+use chrono::{Datelike, NaiveDate};
+use lib_test_helpers::wait::WaitPolicy;
+use thirtyfour::prelude::*;
+
+pub const DEFAULT_FORMAT: &str = "%Y-%m-%d";
+const OPEN_PANEL: &str = ".ant-picker-dropdown:not(.ant-picker-dropdown-hidden)";
+const MAX_MONTH_STEPS: usize = 240;
+
+async fn input_of(driver: &WebDriver, picker: &By) -> WebDriverResult<WebElement> {
+    let policy = WaitPolicy::global();
+    let element = driver
+        .query(picker.clone())
+        .wait(policy.timeout, policy.poll_interval)
+        .first()
+        .await?;
+    if element.tag_name().await?.eq_ignore_ascii_case("input") {
+        return Ok(element);
+    }
+    element.find(By::Css("input")).await
+}
+
+pub async fn picked_value(driver: &WebDriver, picker: By) -> WebDriverResult<String> {
+    let input = input_of(driver, &picker).await?;
+    Ok(input.value().await?.unwrap_or_default())
+}
+
+/// Types the date into the picker input, which Ant Design parses with the picker's
+/// own `format`, so `format` must match what the component is configured with.
+pub async fn type_date(
+    driver: &WebDriver,
+    picker: By,
+    date: NaiveDate,
+    format: &str,
+) -> WebDriverResult<()> {
+    let input = input_of(driver, &picker).await?;
+    let formatted = date.format(format).to_string();
+
+    input.scroll_into_view().await?;
+    input.click().await?;
+    input.send_keys(Key::Control + "a").await?;
+    input.send_keys(Key::Backspace).await?;
+    input.send_keys(&formatted).await?;
+    input.send_keys(Key::Enter).await?;
+
+    WaitPolicy::global()
+        .until(&format!("date picker to show {}", formatted), || async {
+            let value = input.value().await?.unwrap_or_default();
+            Ok((value == formatted).then_some(()))
+        })
+        .await
+}
+
+async fn panel_month(panel: &WebElement) -> WebDriverResult<Option<NaiveDate>> {
+    let Some(cell) = panel
+        .find_all(By::Css("td.ant-picker-cell-in-view[title]"))
+        .await?
+        .into_iter()
+        .next()
+    else {
+        return Ok(None);
+    };
+    let title = cell.attr("title").await?.unwrap_or_default();
+    Ok(NaiveDate::parse_from_str(&title, "%Y-%m-%d").ok())
+}
+
+/// Picks the date by navigating the calendar panel, for pickers with `inputReadOnly`.
+pub async fn pick_date(driver: &WebDriver, picker: By, date: NaiveDate) -> WebDriverResult<()> {
+    let input = input_of(driver, &picker).await?;
+    input.scroll_into_view().await?;
+    input.click().await?;
+
+    let panel = WaitPolicy::global()
+        .until("date picker panel to open", || async {
+            Ok(visible(driver, OPEN_PANEL).await?.pop())
+        })
+        .await?;
+
+    let target = (date.year(), date.month());
+    for _ in 0..MAX_MONTH_STEPS {
+        let Some(shown) = panel_month(&panel).await? else {
+            return Err(not_found("Date picker panel has no visible cells"));
+        };
+        let shown = (shown.year(), shown.month());
+        if shown == target {
+            let title = date.format("%Y-%m-%d").to_string();
+            let cell = panel
+                .find(By::Css(format!(
+                    "td.ant-picker-cell-in-view[title='{}']",
+                    title
+                )))
+                .await?;
+            if cell
+                .class_name()
+                .await?
+                .unwrap_or_default()
+                .contains("ant-picker-cell-disabled")
+            {
+                return Err(not_found(format!(
+                    "Date {} is disabled in the picker",
+                    title
+                )));
+            }
+            cell.click().await?;
+            return Ok(());
+        }
+
+        let button = if shown < target {
+            ".ant-picker-header-next-btn"
+        } else {
+            ".ant-picker-header-prev-btn"
+        };
+        panel.find(By::Css(button)).await?.click().await?;
+    }
+
+    Err(not_found(format!(
+        "Could not navigate the date picker to {}",
+        date
+    )))
+}
+
+pub async fn clear_date(driver: &WebDriver, picker: By) -> WebDriverResult<()> {
+    let element = driver.find(picker.clone()).await?;
+    driver
+        .action_chain()
+        .move_to_element_center(&element)
+        .perform()
+        .await?;
+    let root = element
+        .query(By::XPath(
+            "./ancestor-or-self::*[contains(@class,'ant-picker')][1]",
+        ))
+        .first()
+        .await?;
+    root.find(By::Css(".ant-picker-clear"))
+        .await?
+        .click()
+        .await?;
+    WaitPolicy::global()
+        .until("date picker to be cleared", || async {
+            Ok(picked_value(driver, picker.clone())
+                .await?
+                .is_empty()
+                .then_some(()))
+        })
+        .await
+}
@@ -0,0 +1,64 @@
+use super::text_of;
+use lib_test_helpers::wait::WaitPolicy;
+use thirtyfour::prelude::*;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
+}
+
+const ERROR_ITEM: &str = ".ant-form-item-has-error";
+const ERROR_MESSAGE: &str = ".ant-form-item-explain-error";
+
+pub async fn field_errors(driver: &WebDriver) -> WebDriverResult<Vec<FieldError>> {
+    let mut errors = Vec::new();
+    for item in driver.find_all(By::Css(ERROR_ITEM)).await? {
+        let field = match item.find(By::Css(".ant-form-item-label label")).await {
+            Ok(label) => text_of(&label).await,
+            Err(_) => String::new(),
+        };
+        for message in item.find_all(By::Css(ERROR_MESSAGE)).await? {
+            let message = text_of(&message).await;
+            if !message.is_empty() {
+                errors.push(FieldError {
+                    field: field.clone(),
+                    message,
+                });
+            }
+        }
+    }
+    Ok(errors)
+}
+
+pub async fn field_error(driver: &WebDriver, field: &str) -> WebDriverResult<Option<String>> {
+    Ok(field_errors(driver)
+        .await?
+        .into_iter()
+        .find(|e| e.field.eq_ignore_ascii_case(field.trim()))
+        .map(|e| e.message))
+}
+
+pub async fn wait_for_field_error(driver: &WebDriver, field: &str) -> WebDriverResult<String> {
+    WaitPolicy::global()
+        .until(&format!("validation error on '{}'", field), || {
+            field_error(driver, field)
+        })
+        .await
+}
+
+pub async fn expect_no_errors(driver: &WebDriver) -> WebDriverResult<()> {
+    let errors = field_errors(driver).await?;
+    if errors.is_empty() {
+        return Ok(());
+    }
+    let summary = errors
+        .iter()
+        .map(|e| format!("{}: {}", e.field, e.message))
+        .collect::<Vec<_>>()
+        .join("; ");
+    Err(WebDriverError::ParseError(format!(
+        "Form has validation errors: {}",
+        summary
+    )))
+}
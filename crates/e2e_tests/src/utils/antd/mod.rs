@@ -0,0 +1,39 @@
+pub mod date_picker;
+pub mod form;
+pub mod modal;
+pub mod select;
+pub mod table;
+pub mod toast;
+
+use thirtyfour::prelude::*;
+
+pub(crate) fn not_found(what: impl Into<String>) -> WebDriverError {
+    WebDriverError::NotFound(what.into(), String::from("None"))
+}
+
+pub(crate) async fn visible(
+    driver: &WebDriver,
+    selector: &str,
+) -> WebDriverResult<Vec<WebElement>> {
+    let mut shown = Vec::new();
+    for element in driver.find_all(By::Css(selector)).await? {
+        if element.is_displayed().await.unwrap_or(false) {
+            shown.push(element);
+        }
+    }
+    Ok(shown)
+}
+
+pub(crate) async fn text_of(element: &WebElement) -> String {
+    element.text().await.unwrap_or_default().trim().to_string()
+}
+
+pub(crate) async fn has_class(element: &WebElement, class: &str) -> bool {
+    element
+        .class_name()
+        .await
+        .ok()
+        .flatten()
+        .map(|classes| classes.split_whitespace().any(|c| c == class))
+        .unwrap_or(false)
+}
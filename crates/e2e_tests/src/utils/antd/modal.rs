@@ -0,0 +1,96 @@
+use super::{not_found, text_of, visible};
+use lib_test_helpers::wait::WaitPolicy;
+use thirtyfour::prelude::*;
+
+const OPEN_MODAL: &str = ".ant-modal-wrap:not([style*='display: none']) .ant-modal";
+
+pub async fn wait_for_modal(
+    driver: &WebDriver,
+    title: Option<&str>,
+) -> WebDriverResult<WebElement> {
+    let what = match title {
+        Some(title) => format!("modal '{}'", title),
+        None => "a modal".to_string(),
+    };
+    WaitPolicy::global()
+        .until(&what, || async {
+            for modal in visible(driver, OPEN_MODAL).await?.into_iter().rev() {
+                let Some(title) = title else {
+                    return Ok(Some(modal));
+                };
+                if modal_title(&modal).await.eq_ignore_ascii_case(title.trim()) {
+                    return Ok(Some(modal));
+                }
+            }
+            Ok(None)
+        })
+        .await
+}
+
+pub async fn modal_title(modal: &WebElement) -> String {
+    match modal
+        .find(By::Css(".ant-modal-title, .ant-modal-confirm-title"))
+        .await
+    {
+        Ok(title) => text_of(&title).await,
+        Err(_) => String::new(),
+    }
+}
+
+pub async fn modal_body(modal: &WebElement) -> WebDriverResult<String> {
+    let body = modal
+        .find(By::Css(".ant-modal-body, .ant-modal-confirm-content"))
+        .await?;
+    Ok(text_of(&body).await)
+}
+
+pub async fn wait_for_modal_closed(driver: &WebDriver) -> WebDriverResult<()> {
+    WaitPolicy::global()
+        .until("modal to close", || async {
+            Ok(visible(driver, OPEN_MODAL).await?.is_empty().then_some(()))
+        })
+        .await
+}
+
+async fn click_footer_button(modal: &WebElement, primary: bool) -> WebDriverResult<()> {
+    let selector = if primary {
+        ".ant-modal-footer .ant-btn-primary, .ant-modal-confirm-btns .ant-btn-primary"
+    } else {
+        ".ant-modal-footer .ant-btn:not(.ant-btn-primary), .ant-modal-confirm-btns .ant-btn:not(.ant-btn-primary)"
+    };
+    let button = modal
+        .find_all(By::Css(selector))
+        .await?
+        .into_iter()
+        .next()
+        .ok_or_else(|| {
+            not_found(if primary {
+                "Modal has no confirm button"
+            } else {
+                "Modal has no cancel button"
+            })
+        })?;
+    button.click().await
+}
+
+pub async fn confirm_modal(driver: &WebDriver) -> WebDriverResult<()> {
+    let modal = wait_for_modal(driver, None).await?;
+    click_footer_button(&modal, true).await?;
+    wait_for_modal_closed(driver).await
+}
+
+pub async fn cancel_modal(driver: &WebDriver) -> WebDriverResult<()> {
+    let modal = wait_for_modal(driver, None).await?;
+    click_footer_button(&modal, false).await?;
+    wait_for_modal_closed(driver).await
+}
+
+pub async fn close_modal(driver: &WebDriver) -> WebDriverResult<()> {
+    let modal = wait_for_modal(driver, None).await?;
+    modal
+        .find(By::Css(".ant-modal-close"))
+        .await?
+        .click()
+        .await?;
+    wait_for_modal_closed(driver).await
+}
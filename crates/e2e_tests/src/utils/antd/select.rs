@@ -0,0 +1,224 @@
+use super::{has_class, not_found, text_of, visible};
+use lib_test_helpers::wait::WaitPolicy;
+use thirtyfour::prelude::*;
+
+const OPEN_DROPDOWN: &str = ".ant-select-dropdown:not(.ant-select-dropdown-hidden)";
+
+/// Resolves the `.ant-select` root, since form ids usually land on the inner search input.
+async fn root_of(element: WebElement) -> WebElement {
+    element
+        .query(By::XPath(
+            "./ancestor-or-self::*[contains(concat(' ', normalize-space(@class), ' '), ' ant-select ')][1]",
+        ))
+        .nowait()
+        .first()
+        .await
+        .unwrap_or(element)
+}
+
+async fn open(driver: &WebDriver, select: &By) -> WebDriverResult<WebElement> {
+    let policy = WaitPolicy::global();
+    let element = driver
+        .query(select.clone())
+        .wait(policy.timeout, policy.poll_interval)
+        .first()
+        .await?;
+    let element = root_of(element).await;
+    element.scroll_into_view().await?;
+
+    if visible(driver, OPEN_DROPDOWN).await?.is_empty() {
+        element.click().await?;
+    }
+    WaitPolicy::global()
+        .until("select dropdown to open", || async {
+            Ok(visible(driver, OPEN_DROPDOWN).await?.pop())
+        })
+        .await?;
+    Ok(element)
+}
+
+async fn search_input(select: &WebElement) -> Option<WebElement> {
+    select
+        .query(By::Css("input.ant-select-selection-search-input, input"))
+        .nowait()
+        .first()
+        .await
+        .ok()
+}
+
+/// Waits for remote options to finish loading: the dropdown shows a spinner or an empty
+/// placeholder while an async `onSearch` request is in flight.
+async fn wait_for_options(driver: &WebDriver) -> WebDriverResult<Vec<WebElement>> {
+    WaitPolicy::global()
+        .until("select options to load", || async {
+            let dropdowns = visible(driver, OPEN_DROPDOWN).await?;
+            let Some(dropdown) = dropdowns.last() else {
+                return Ok(None);
+            };
+            if !dropdown
+                .find_all(By::Css(".ant-spin-spinning"))
+                .await?
+                .is_empty()
+            {
+                return Ok(None);
+            }
+            let options = dropdown
+                .find_all(By::Css(".ant-select-item-option"))
+                .await?;
+            Ok((!options.is_empty()).then_some(options))
+        })
+        .await
+}
+
+async fn option_label(option: &WebElement) -> String {
+    match option.attr("title").await.ok().flatten() {
+        Some(title) if !title.trim().is_empty() => title.trim().to_string(),
+        _ => text_of(option).await,
+    }
+}
+
+async fn click_option(driver: &WebDriver, option_text: &str) -> WebDriverResult<()> {
+    let wanted = option_text.trim();
+    let options = wait_for_options(driver).await?;
+
+    let mut available = Vec::new();
+    for option in options {
+        let label = option_label(&option).await;
+        if label.eq_ignore_ascii_case(wanted) {
+            if has_class(&option, "ant-select-item-option-disabled").await {
+                return Err(not_found(format!("Select option '{}' is disabled", wanted)));
+            }
+            option.scroll_into_view().await?;
+            option.click().await?;
+            return Ok(());
+        }
+        available.push(label);
+    }
+
+    Err(not_found(format!(
+        "Select option '{}' not found (available: {})",
+        wanted,
+        available.join(", ")
+    )))
+}
+
+async fn close(driver: &WebDriver, select: &WebElement) -> WebDriverResult<()> {
+    if !visible(driver, OPEN_DROPDOWN).await?.is_empty() {
+        match search_input(select).await {
+            Some(input) => input.send_keys(Key::Escape).await?,
+            None => select.send_keys(Key::Escape).await?,
+        }
+    }
+    Ok(())
+}
+
+pub async fn selected_values(driver: &WebDriver, select: By) -> WebDriverResult<Vec<String>> {
+    let element = root_of(driver.find(select).await?).await;
+    let mut values = Vec::new();
+    for item in element
+        .find_all(By::Css(
+            ".ant-select-selection-item-content, .ant-select-selection-item:not(.ant-select-selection-overflow-item)",
+        ))
+        .await?
+    {
+        let label = match item.attr("title").await.ok().flatten() {
+            Some(title) if !title.is_empty() => title,
+            _ => text_of(&item).await,
+        };
+        if !label.is_empty() && !values.contains(&label) {
+            values.push(label);
+        }
+    }
+    Ok(values)
+}
+
+async fn wait_for_selected(
+    driver: &WebDriver,
+    select: &By,
+    option_text: &str,
+) -> WebDriverResult<()> {
+    WaitPolicy::global()
+        .until(
+            &format!("'{}' to show as selected", option_text),
+            || async {
+                let values = selected_values(driver, select.clone()).await?;
+                Ok(values
+                    .iter()
+                    .any(|v| v.eq_ignore_ascii_case(option_text.trim()))
+                    .then_some(()))
+            },
+        )
+        .await
+}
+
+pub async fn select_option(
+    driver: &WebDriver,
+    select: By,
+    option_text: &str,
+) -> WebDriverResult<()> {
+    open(driver, &select).await?;
+    click_option(driver, option_text).await?;
+    wait_for_selected(driver, &select, option_text).await
+}
+
+pub async fn search_and_select(
+    driver: &WebDriver,
+    select: By,
+    query: &str,
+    option_text: &str,
+) -> WebDriverResult<()> {
+    let element = open(driver, &select).await?;
+    match search_input(&element).await {
+        Some(input) => input.send_keys(query).await?,
+        None => element.send_keys(query).await?,
+    }
+    click_option(driver, option_text).await?;
+    wait_for_selected(driver, &select, option_text).await
+}
+
+pub async fn select_multiple(
+    driver: &WebDriver,
+    select: By,
+    option_texts: &[&str],
+) -> WebDriverResult<()> {
+    let element = open(driver, &select).await?;
+    let already = selected_values(driver, select.clone()).await?;
+    for option_text in option_texts {
+        if already
+            .iter()
+            .any(|v| v.eq_ignore_ascii_case(option_text.trim()))
+        {
+            continue;
+        }
+        if let Some(input) = search_input(&element).await {
+            input.send_keys(*option_text).await?;
+        }
+        click_option(driver, option_text).await?;
+        wait_for_selected(driver, &select, option_text).await?;
+    }
+    close(driver, &element).await
+}
+
+pub async fn clear_selection(driver: &WebDriver, select: By) -> WebDriverResult<()> {
+    let policy = WaitPolicy::global();
+    let element = root_of(driver.find(select.clone()).await?).await;
+    driver
+        .action_chain()
+        .move_to_element_center(&element)
+        .perform()
+        .await?;
+    let clear = element
+        .query(By::Css(".ant-select-clear"))
+        .wait(policy.timeout, policy.poll_interval)
+        .first()
+        .await?;
+    clear.click().await?;
+    WaitPolicy::global()
+        .until("select to be cleared", || async {
+            Ok(selected_values(driver, select.clone())
+                .await?
+                .is_empty()
+                .then_some(()))
+        })
+        .await
+}
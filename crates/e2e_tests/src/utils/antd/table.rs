@@ -0,0 +1,205 @@
+use super::{has_class, not_found, text_of};
+use lib_test_helpers::wait::WaitPolicy;
+use std::collections::BTreeMap;
+use thirtyfour::prelude::*;
+
+const MAX_PAGES: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+impl SortOrder {
+    fn aria(&self) -> &'static str {
+        match self {
+            SortOrder::Ascending => "ascending",
+            SortOrder::Descending => "descending",
+        }
+    }
+}
+
+pub struct AntTable<'a> {
+    driver: &'a WebDriver,
+    root: By,
+}
+
+impl<'a> AntTable<'a> {
+    pub fn new(driver: &'a WebDriver, root: By) -> Self {
+        Self { driver, root }
+    }
+
+    async fn root(&self) -> WebDriverResult<WebElement> {
+        let policy = WaitPolicy::global();
+        self.driver
+            .query(self.root.clone())
+            .wait(policy.timeout, policy.poll_interval)
+            .first()
+            .await
+    }
+
+    pub async fn wait_loaded(&self) -> WebDriverResult<()> {
+        WaitPolicy::global()
+            .until("table to finish loading", || async {
+                let root = self.root().await?;
+                let spinning = root.find_all(By::Css(".ant-spin-spinning")).await?;
+                Ok(spinning.is_empty().then_some(()))
+            })
+            .await
+    }
+
+    pub async fn headers(&self) -> WebDriverResult<Vec<String>> {
+        let root = self.root().await?;
+        let mut headers = Vec::new();
+        for cell in root.find_all(By::Css("thead th.ant-table-cell")).await? {
+            headers.push(text_of(&cell).await);
+        }
+        Ok(headers)
+    }
+
+    pub async fn rows(&self) -> WebDriverResult<Vec<Vec<String>>> {
+        self.wait_loaded().await?;
+        let root = self.root().await?;
+        let mut rows = Vec::new();
+        for row in root
+            .find_all(By::Css(
+                "tbody tr.ant-table-row:not(.ant-table-measure-row):not(.ant-table-placeholder)",
+            ))
+            .await?
+        {
+            let mut cells = Vec::new();
+            for cell in row.find_all(By::Css("td.ant-table-cell")).await? {
+                cells.push(text_of(&cell).await);
+            }
+            rows.push(cells);
+        }
+        Ok(rows)
+    }
+
+    pub async fn rows_as_maps(&self) -> WebDriverResult<Vec<BTreeMap<String, String>>> {
+        let headers = self.headers().await?;
+        Ok(self
+            .rows()
+            .await?
+            .into_iter()
+            .map(|row| headers.iter().cloned().zip(row).collect())
+            .collect())
+    }
+
+    pub async fn find_row(
+        &self,
+        column: &str,
+        value: &str,
+    ) -> WebDriverResult<Option<BTreeMap<String, String>>> {
+        Ok(self
+            .rows_as_maps()
+            .await?
+            .into_iter()
+            .find(|row| row.get(column).map(|v| v == value).unwrap_or(false)))
+    }
+
+    async fn pagination_item(&self, selector: &str) -> WebDriverResult<Option<WebElement>> {
+        let root = self.root().await?;
+        let pagination = root
+            .query(By::XPath(
+                "./ancestor-or-self::*[contains(@class,'ant-table-wrapper')][1]//ul[contains(@class,'ant-pagination')]",
+            ))
+            .nowait()
+            .first()
+            .await;
+        match pagination {
+            Ok(pagination) => Ok(pagination
+                .find_all(By::Css(selector))
+                .await?
+                .into_iter()
+                .next()),
+            Err(_) => Ok(None),
+        }
+    }
+
+    pub async fn current_page(&self) -> WebDriverResult<usize> {
+        match self.pagination_item(".ant-pagination-item-active").await? {
+            Some(item) => Ok(text_of(&item).await.parse().unwrap_or(1)),
+            None => Ok(1),
+        }
+    }
+
+    /// Returns `false` when already on the last page.
+    pub async fn next_page(&self) -> WebDriverResult<bool> {
+        let Some(next) = self.pagination_item(".ant-pagination-next").await? else {
+            return Ok(false);
+        };
+        if has_class(&next, "ant-pagination-disabled").await {
+            return Ok(false);
+        }
+        let before = self.current_page().await?;
+        next.click().await?;
+        WaitPolicy::global()
+            .until("table to move to the next page", || async {
+                Ok((self.current_page().await? != before).then_some(()))
+            })
+            .await?;
+        self.wait_loaded().await?;
+        Ok(true)
+    }
+
+    pub async fn go_to_page(&self, page: usize) -> WebDriverResult<()> {
+        let item = self
+            .pagination_item(&format!(".ant-pagination-item-{}", page))
+            .await?
+            .ok_or_else(|| not_found(format!("Table has no page {}", page)))?;
+        item.click().await?;
+        WaitPolicy::global()
+            .until(&format!("table page {}", page), || async {
+                Ok((self.current_page().await? == page).then_some(()))
+            })
+            .await?;
+        self.wait_loaded().await
+    }
+
+    pub async fn all_rows(&self) -> WebDriverResult<Vec<BTreeMap<String, String>>> {
+        let mut rows = self.rows_as_maps().await?;
+        for _ in 0..MAX_PAGES {
+            if !self.next_page().await? {
+                break;
+            }
+            rows.extend(self.rows_as_maps().await?);
+        }
+        Ok(rows)
+    }
+
+    async fn sort_header(&self, column: &str) -> WebDriverResult<WebElement> {
+        let root = self.root().await?;
+        for header in root
+            .find_all(By::Css("thead th.ant-table-column-has-sorters"))
+            .await?
+        {
+            if text_of(&header).await.eq_ignore_ascii_case(column.trim()) {
+                return Ok(header);
+            }
+        }
+        Err(not_found(format!(
+            "Table has no sortable column '{}'",
+            column
+        )))
+    }
+
+    /// Clicks the header until Ant Design reports the requested order; sorting cycles
+    /// through ascending, descending and unsorted.
+    pub async fn sort_by(&self, column: &str, order: SortOrder) -> WebDriverResult<()> {
+        for _ in 0..3 {
+            let header = self.sort_header(column).await?;
+            if header.attr("aria-sort").await?.as_deref() == Some(order.aria()) {
+                return self.wait_loaded().await;
+            }
+            header.click().await?;
+            self.wait_loaded().await?;
+        }
+        Err(not_found(format!(
+            "Could not sort '{}' {}",
+            column,
+            order.aria()
+        )))
+    }
+}
@@ -0,0 +1,100 @@
+use super::{text_of, visible};
+use lib_test_helpers::wait::WaitPolicy;
+use thirtyfour::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToastKind {
+    Success,
+    Error,
+    Warning,
+    Info,
+}
+
+impl ToastKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ToastKind::Success => "success",
+            ToastKind::Error => "error",
+            ToastKind::Warning => "warning",
+            ToastKind::Info => "info",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Notification {
+    pub title: String,
+    pub description: String,
+}
+
+pub async fn wait_for_message(
+    driver: &WebDriver,
+    kind: Option<ToastKind>,
+) -> WebDriverResult<String> {
+    let selector = match kind {
+        Some(kind) => format!(".ant-message-notice .ant-message-{}", kind.as_str()),
+        None => ".ant-message-notice .ant-message-notice-content".to_string(),
+    };
+    let what = match kind {
+        Some(kind) => format!("{} message", kind.as_str()),
+        None => "a message".to_string(),
+    };
+    WaitPolicy::global()
+        .until(&what, || async {
+            match visible(driver, &selector).await?.pop() {
+                Some(message) => Ok(Some(text_of(&message).await)),
+                None => Ok(None),
+            }
+        })
+        .await
+}
+
+pub async fn expect_message(
+    driver: &WebDriver,
+    kind: ToastKind,
+    contains: &str,
+) -> WebDriverResult<String> {
+    let text = wait_for_message(driver, Some(kind)).await?;
+    if text.to_lowercase().contains(&contains.to_lowercase()) {
+        Ok(text)
+    } else {
+        Err(WebDriverError::ParseError(format!(
+            "Expected {} message containing '{}', got '{}'",
+            kind.as_str(),
+            contains,
+            text
+        )))
+    }
+}
+
+pub async fn wait_for_notification(driver: &WebDriver) -> WebDriverResult<Notification> {
+    WaitPolicy::global()
+        .until("a notification", || async {
+            let Some(notice) = visible(driver, ".ant-notification-notice").await?.pop() else {
+                return Ok(None);
+            };
+            let title = match notice
+                .find(By::Css(".ant-notification-notice-message"))
+                .await
+            {
+                Ok(el) => text_of(&el).await,
+                Err(_) => String::new(),
+            };
+            let description = match notice
+                .find(By::Css(".ant-notification-notice-description"))
+                .await
+            {
+                Ok(el) => text_of(&el).await,
+                Err(_) => String::new(),
+            };
+            Ok(Some(Notification { title, description }))
+        })
+        .await
+}
+
+pub async fn close_notifications(driver: &WebDriver) -> WebDriverResult<()> {
+    for close in visible(driver, ".ant-notification-notice-close").await? {
+        close.click().await.ok();
+    }
+    Ok(())
+}
@@ -0,0 +1,327 @@
+use crate::artifacts::{full_page_screenshot, slug};
+use crate::suite::CheckOutcome;
+use image::{ImageFormat, Rgba, RgbaImage};
+use lib_test_helpers::config::get_config;
+use lib_test_helpers::reporting::Attachment;
+use lib_test_helpers::wait;
+use serde_json::Value;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use thirtyfour::prelude::*;
+
+const BASELINE_DIR: &str = "crates/e2e_tests/visual_baselines";
+/// Per-pixel colour distance (0..1) above which a pixel counts as changed.
+const DEFAULT_THRESHOLD: f64 = 0.1;
+/// Share of changed pixels a check may have and still pass.
+const DEFAULT_TOLERANCE: f64 = 0.001;
+const MASK_COLOR: Rgba<u8> = Rgba([255, 0, 255, 255]);
+// Largest possible YIQ distance, used to normalise the threshold.
+const MAX_YIQ_DELTA: f64 = 35215.0;
+
+// Text that changes from run to run: dates, times, UUIDs and generated reference numbers.
+const DYNAMIC_TEXT: &[&str] = &[
+    r"\b\d{1,2}[/-]\d{1,2}[/-]\d{2,4}\b",
+    r"\b\d{4}-\d{2}-\d{2}\b",
+    r"\b\d{1,2}\s(Jan|Feb|Mar|Apr|May|Jun|Jul|Aug|Sep|Oct|Nov|Dec)[a-z]*,?\s\d{4}\b",
+    r"\b\d{1,2}:\d{2}(:\d{2})?\s?(AM|PM|am|pm)?\b",
+    r"\b[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}\b",
+    r"\b[A-Z]{2,}[-/]?\d{4,}\b",
+];
+
+// Bounding boxes (document coordinates) of the innermost elements whose text matches one of
+// the patterns.
+const TEXT_RECTS: &str = r#"
+const patterns = arguments[0].map(p => new RegExp(p));
+const rects = [];
+const walker = document.createTreeWalker(document.body, NodeFilter.SHOW_TEXT);
+while (walker.nextNode()) {
+    const node = walker.currentNode;
+    if (!patterns.some(p => p.test(node.textContent))) continue;
+    const el = node.parentElement;
+    if (!el || el.offsetParent === null) continue;
+    const r = el.getBoundingClientRect();
+    rects.push([r.left + window.scrollX, r.top + window.scrollY, r.width, r.height]);
+}
+return rects;
+"#;
+
+const VIEWPORT: &str = r#"
+return [window.devicePixelRatio || 1, window.scrollX, window.scrollY, window.innerHeight];
+"#;
+
+/// True when `--update-baselines` (or `UPDATE_BASELINES=1`) asks for new screenshots to be
+/// accepted as the baseline instead of compared.
+pub fn update_baselines() -> bool {
+    get_config().update_baselines
+}
+
+#[derive(Debug, Clone)]
+enum Target {
+    Page,
+    Element(By),
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Rect {
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct VisualCheck {
+    name: String,
+    target: Target,
+    masks: Vec<By>,
+    mask_patterns: Vec<String>,
+    threshold: f64,
+    tolerance: f64,
+}
+
+impl VisualCheck {
+    fn new(name: &str, target: Target) -> Self {
+        Self {
+            name: name.to_string(),
+            target,
+            masks: Vec::new(),
+            mask_patterns: DYNAMIC_TEXT.iter().map(|p| p.to_string()).collect(),
+            threshold: DEFAULT_THRESHOLD,
+            tolerance: DEFAULT_TOLERANCE,
+        }
+    }
+
+    pub fn page(name: &str) -> Self {
+        Self::new(name, Target::Page)
+    }
+
+    pub fn element(name: &str, locator: By) -> Self {
+        Self::new(name, Target::Element(locator))
+    }
+
+    /// Paints over every element matching `locator` before comparing.
+    pub fn mask(mut self, locator: By) -> Self {
+        self.masks.push(locator);
+        self
+    }
+
+    /// Paints over elements whose text matches the (JavaScript) regular expression.
+    pub fn mask_text(mut self, pattern: &str) -> Self {
+        self.mask_patterns.push(pattern.to_string());
+        self
+    }
+
+    pub fn threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    pub fn tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    fn baseline_path(&self) -> PathBuf {
+        PathBuf::from(BASELINE_DIR).join(format!("{}.png", slug(&self.name)))
+    }
+
+    pub async fn run(
+        &self,
+        driver: &WebDriver,
+        reports_dir: &Path,
+    ) -> WebDriverResult<CheckOutcome> {
+        wait::wait_for_page_ready(driver).await?;
+        let viewport: Vec<f64> = driver.execute(VIEWPORT, Vec::new()).await?.convert()?;
+        let (dpr, scroll_x, scroll_y, inner_height) =
+            (viewport[0], viewport[1], viewport[2], viewport[3]);
+
+        let (png, origin) = match &self.target {
+            Target::Page => {
+                let png = full_page_screenshot(driver).await?;
+                (png, (0.0, 0.0))
+            }
+            Target::Element(locator) => {
+                let element = driver.find(locator.clone()).await?;
+                wait::wait_for_stable(&element).await?;
+                let rect = element.rect().await?;
+                (element.screenshot_as_png().await?, (rect.x, rect.y))
+            }
+        };
+        let mut actual = image::load_from_memory(&png)
+            .map_err(|e| WebDriverError::ParseError(format!("Unreadable screenshot: {}", e)))?
+            .to_rgba8();
+
+        // Non-Chrome browsers fall back to a viewport screenshot, which starts at the scroll
+        // position rather than the top of the document.
+        let origin = match self.target {
+            Target::Page if (actual.height() as f64) <= inner_height * dpr + 1.0 => {
+                (scroll_x, scroll_y)
+            }
+            _ => origin,
+        };
+        for rect in self.mask_rects(driver).await? {
+            paint(&mut actual, rect, origin, dpr);
+        }
+
+        let baseline_path = self.baseline_path();
+        if update_baselines() {
+            fs::create_dir_all(BASELINE_DIR)?;
+            save_png(&actual, &baseline_path)?;
+            println!("📸 Baseline saved: {}", baseline_path.display());
+            return Ok(CheckOutcome::passed());
+        }
+
+        let out_dir = reports_dir.join("visual").join(format!(
+            "{}-{}",
+            slug(&self.name),
+            chrono::Local::now().format("%Y%m%d-%H%M%S")
+        ));
+        let attach = |file: &str, label: &str, img: &RgbaImage| -> io::Result<Attachment> {
+            fs::create_dir_all(&out_dir)?;
+            let path = out_dir.join(file);
+            save_png(img, &path)?;
+            let relative = path.strip_prefix(reports_dir).unwrap_or(&path);
+            Ok(Attachment::new(
+                label,
+                relative.to_string_lossy().replace('\\', "/"),
+            ))
+        };
+
+        if !baseline_path.exists() {
+            return Ok(CheckOutcome::failed(format!(
+                "No baseline at {}; review the proposed one and rerun with --update-baselines",
+                baseline_path.display()
+            ))
+            .with_attachment(attach(
+                "proposed-baseline.png",
+                "Proposed baseline",
+                &actual,
+            )?));
+        }
+        let baseline = image::open(&baseline_path)
+            .map_err(|e| WebDriverError::ParseError(format!("Unreadable baseline: {}", e)))?
+            .to_rgba8();
+
+        if baseline.dimensions() != actual.dimensions() {
+            return Ok(CheckOutcome::failed(format!(
+                "Size changed from {:?} to {:?}",
+                baseline.dimensions(),
+                actual.dimensions()
+            ))
+            .with_attachment(attach("baseline.png", "Baseline", &baseline)?)
+            .with_attachment(attach("actual.png", "Actual", &actual)?));
+        }
+
+        let (diff, changed) = compare(&baseline, &actual, self.threshold);
+        let ratio = changed as f64 / (actual.width() as f64 * actual.height() as f64).max(1.0);
+        let outcome = if ratio <= self.tolerance {
+            CheckOutcome::passed()
+        } else {
+            CheckOutcome::failed(format!(
+                "{:.3}% of pixels differ from the baseline (tolerance {:.3}%)",
+                ratio * 100.0,
+                self.tolerance * 100.0
+            ))
+            .with_attachment(attach("baseline.png", "Baseline", &baseline)?)
+            .with_attachment(attach("actual.png", "Actual", &actual)?)
+            .with_attachment(attach("diff.png", "Diff", &diff)?)
+        };
+        Ok(outcome.with_metric("visual_diff_pct", ratio * 100.0))
+    }
+
+    async fn mask_rects(&self, driver: &WebDriver) -> WebDriverResult<Vec<Rect>> {
+        let mut rects = Vec::new();
+        for locator in &self.masks {
+            for element in driver.find_all(locator.clone()).await? {
+                let r = element.rect().await?;
+                rects.push(Rect {
+                    x: r.x,
+                    y: r.y,
+                    width: r.width,
+                    height: r.height,
+                });
+            }
+        }
+        if !self.mask_patterns.is_empty() {
+            let patterns = Value::from(self.mask_patterns.clone());
+            let found: Vec<Vec<f64>> = driver
+                .execute(TEXT_RECTS, vec![patterns])
+                .await?
+                .convert()?;
+            rects.extend(found.iter().filter(|r| r.len() == 4).map(|r| Rect {
+                x: r[0],
+                y: r[1],
+                width: r[2],
+                height: r[3],
+            }));
+        }
+        Ok(rects)
+    }
+}
+
+fn save_png(img: &RgbaImage, path: &Path) -> io::Result<()> {
+    img.save_with_format(path, ImageFormat::Png)
+        .map_err(|e| io::Error::other(format!("Could not write {}: {}", path.display(), e)))
+}
+
+fn paint(img: &mut RgbaImage, rect: Rect, origin: (f64, f64), dpr: f64) {
+    let x0 = ((rect.x - origin.0) * dpr).floor().max(0.0) as u32;
+    let y0 = ((rect.y - origin.1) * dpr).floor().max(0.0) as u32;
+    let x1 = (((rect.x + rect.width - origin.0) * dpr).ceil().max(0.0) as u32).min(img.width());
+    let y1 = (((rect.y + rect.height - origin.1) * dpr).ceil().max(0.0) as u32).min(img.height());
+    for y in y0..y1 {
+        for x in x0..x1 {
+            img.put_pixel(x, y, MASK_COLOR);
+        }
+    }
+}
+
+fn blend_white(c: u8, alpha: f64) -> f64 {
+    255.0 + (c as f64 - 255.0) * alpha
+}
+
+// YIQ colour distance as used by pixelmatch: weights brightness over hue, which is closer to
+// what a reviewer notices than raw RGB distance.
+fn yiq_delta(a: &Rgba<u8>, b: &Rgba<u8>) -> f64 {
+    let rgb = |p: &Rgba<u8>| {
+        let alpha = p[3] as f64 / 255.0;
+        (
+            blend_white(p[0], alpha),
+            blend_white(p[1], alpha),
+            blend_white(p[2], alpha),
+        )
+    };
+    let (r1, g1, b1) = rgb(a);
+    let (r2, g2, b2) = rgb(b);
+    let y = |r: f64, g: f64, b: f64| r * 0.29889531 + g * 0.58662247 + b * 0.11448223;
+    let i = |r: f64, g: f64, b: f64| r * 0.59597799 - g * 0.2741761 - b * 0.32180189;
+    let q = |r: f64, g: f64, b: f64| r * 0.21147017 - g * 0.52261711 + b * 0.31114694;
+    let dy = y(r1, g1, b1) - y(r2, g2, b2);
+    let di = i(r1, g1, b1) - i(r2, g2, b2);
+    let dq = q(r1, g1, b1) - q(r2, g2, b2);
+    0.5053 * dy * dy + 0.299 * di * di + 0.1957 * dq * dq
+}
+
+/// Returns a diff image (changes in red over a faded copy of the baseline, masks in blue)
+/// and the number of changed pixels.
+fn compare(baseline: &RgbaImage, actual: &RgbaImage, threshold: f64) -> (RgbaImage, u64) {
+    let max_delta = MAX_YIQ_DELTA * threshold * threshold;
+    let mut diff = RgbaImage::new(actual.width(), actual.height());
+    let mut changed = 0;
+    for (x, y, a) in actual.enumerate_pixels() {
+        let b = baseline.get_pixel(x, y);
+        let pixel = if *a == MASK_COLOR && *b == MASK_COLOR {
+            Rgba([200, 220, 255, 255])
+        } else if yiq_delta(a, b) > max_delta {
+            changed += 1;
+            Rgba([255, 0, 0, 255])
+        } else {
+            let luma = (b[0] as f64 * 0.299 + b[1] as f64 * 0.587 + b[2] as f64 * 0.114) as u8;
+            let faded = 255 - ((255 - luma) as f64 * 0.3) as u8;
+            Rgba([faded, faded, faded, 255])
+        };
+        diff.put_pixel(x, y, pixel);
+    }
+    (diff, changed)
+}
@@ -0,0 +1,210 @@
+use crate::driver::isolated_driver;
+use crate::driver_service::free_port;
+use crate::session_file::{restore_session, SessionKey};
+use anyhow::{Context, Result};
+use std::env;
+use std::fs;
+use std::ops::Deref;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use thirtyfour::prelude::*;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// Number of concurrent browsers to use, from `E2E_WORKERS` (default 2), never more than `max`.
+pub fn workers(max: usize) -> usize {
+    env::var("E2E_WORKERS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(2)
+        .clamp(1, max.max(1))
+}
+
+struct Slot {
+    id: usize,
+    driver: WebDriver,
+}
+
+struct PoolInner {
+    idle: Mutex<Vec<Slot>>,
+    permits: Arc<Semaphore>,
+    base_url: String,
+    session: SessionKey,
+    profiles_dir: PathBuf,
+    size: usize,
+}
+
+pub struct BrowserPoolBuilder {
+    size: usize,
+    base_url: String,
+    session: SessionKey,
+    profiles_dir: PathBuf,
+}
+
+impl BrowserPoolBuilder {
+    pub fn session(mut self, key: SessionKey) -> Self {
+        self.session = key;
+        self
+    }
+
+    pub fn profiles_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.profiles_dir = dir.into();
+        self
+    }
+
+    pub async fn launch(self) -> Result<BrowserPool> {
+        let mut slots = Vec::with_capacity(self.size);
+        for id in 0..self.size {
+            let profile = self.profiles_dir.join(format!("slot-{}", id));
+            let _ = fs::remove_dir_all(&profile);
+            fs::create_dir_all(&profile)
+                .with_context(|| format!("Failed to create {}", profile.display()))?;
+
+            match isolated_driver(&profile, free_port()?).await {
+                Ok(driver) => slots.push(Slot { id, driver }),
+                Err(e) => {
+                    for slot in slots {
+                        let _ = slot.driver.quit().await;
+                    }
+                    return Err(e).with_context(|| format!("Failed to launch browser #{}", id));
+                }
+            }
+        }
+        println!("✅ Browser pool ready with {} session(s)", self.size);
+
+        Ok(BrowserPool {
+            inner: Arc::new(PoolInner {
+                idle: Mutex::new(slots),
+                permits: Arc::new(Semaphore::new(self.size)),
+                base_url: self.base_url,
+                session: self.session,
+                profiles_dir: self.profiles_dir,
+                size: self.size,
+            }),
+        })
+    }
+}
+
+pub struct BrowserPool {
+    inner: Arc<PoolInner>,
+}
+
+impl BrowserPool {
+    pub fn builder(size: usize, base_url: impl Into<String>) -> BrowserPoolBuilder {
+        BrowserPoolBuilder {
+            size: size.max(1),
+            base_url: base_url.into(),
+            session: SessionKey::default_user(),
+            profiles_dir: env::temp_dir().join("qa-browser-profiles"),
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.inner.size
+    }
+
+    pub fn session(&self) -> &SessionKey {
+        &self.inner.session
+    }
+
+    /// Waits for a free browser, wipes whatever the previous flow left behind and restores
+    /// the cached login session, if there is a fresh one, before handing it out.
+    pub async fn lease(&self) -> Result<BrowserLease> {
+        let permit = self
+            .inner
+            .permits
+            .clone()
+            .acquire_owned()
+            .await
+            .context("Browser pool is shut down")?;
+        let slot = self
+            .inner
+            .idle
+            .lock()
+            .ok()
+            .and_then(|mut idle| idle.pop())
+            .context("Browser pool has a permit but no idle browser")?;
+
+        let lease = BrowserLease {
+            slot: Some(slot),
+            pool: self.inner.clone(),
+            _permit: permit,
+        };
+        reset(&lease, &self.inner.base_url)
+            .await
+            .with_context(|| format!("Failed to reset browser #{}", lease.id()))?;
+        restore_session(&lease, &self.inner.session, &self.inner.base_url)
+            .await
+            .with_context(|| format!("Failed to restore session in browser #{}", lease.id()))?;
+        Ok(lease)
+    }
+
+    /// Quits every browser and removes the profiles. Waits for outstanding leases first.
+    pub async fn shutdown(self) {
+        let _all = self
+            .inner
+            .permits
+            .acquire_many(self.inner.size as u32)
+            .await;
+        self.inner.permits.close();
+        let slots = match self.inner.idle.lock() {
+            Ok(mut idle) => std::mem::take(&mut *idle),
+            Err(_) => Vec::new(),
+        };
+        for slot in slots {
+            let _ = slot.driver.quit().await;
+        }
+        let _ = fs::remove_dir_all(&self.inner.profiles_dir);
+    }
+}
+
+async fn reset(driver: &WebDriver, base_url: &str) -> WebDriverResult<()> {
+    let windows = driver.windows().await?;
+    if let Some((first, extra)) = windows.split_first() {
+        for handle in extra {
+            driver.switch_to_window(handle.clone()).await?;
+            driver.close_window().await?;
+        }
+        driver.switch_to_window(first.clone()).await?;
+    }
+    driver.goto(base_url).await?;
+    driver.delete_all_cookies().await?;
+    driver
+        .execute(
+            "window.localStorage.clear(); window.sessionStorage.clear();",
+            Vec::new(),
+        )
+        .await?;
+    Ok(())
+}
+
+pub struct BrowserLease {
+    slot: Option<Slot>,
+    pool: Arc<PoolInner>,
+    _permit: OwnedSemaphorePermit,
+}
+
+impl BrowserLease {
+    pub fn id(&self) -> usize {
+        self.slot.as_ref().map(|s| s.id).unwrap_or_default()
+    }
+
+    pub fn driver(&self) -> &WebDriver {
+        self
+    }
+}
+
+impl Deref for BrowserLease {
+    type Target = WebDriver;
+
+    fn deref(&self) -> &WebDriver {
+        &self.slot.as_ref().expect("lease already returned").driver
+    }
+}
+
+impl Drop for BrowserLease {
+    fn drop(&mut self) {
+        if let (Some(slot), Ok(mut idle)) = (self.slot.take(), self.pool.idle.lock()) {
+            idle.push(slot);
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use dotenvy::dotenv;
-use once_cell::sync::Lazy;
-use serde::Deserialize;
+use once_cell::sync::{Lazy, OnceCell};
+use serde::{Deserialize, Deserializer};
+use std::collections::BTreeMap;
 use std::env;
 use std::fs;
 
@@ -15,8 +16,367 @@ pub struct AppConfig {
     pub otp: String,
     pub elder_number: String,
     pub country_code: String,
+    #[serde(default, deserialize_with = "browser_config")]
+    pub browser: BrowserConfig,
+    #[serde(default)]
+    pub fixtures: Fixtures,
+    #[serde(default)]
+    pub wait: WaitSettings,
+    #[serde(default)]
+    pub session: SessionSettings,
+    #[serde(default)]
+    pub a11y: A11ySettings,
+    /// Accept new screenshots as visual baselines instead of comparing against them.
+    #[serde(skip)]
+    pub update_baselines: bool,
 }
 
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct Fixtures {
+    #[serde(default)]
+    pub pos_node_id: String,
+    #[serde(default)]
+    pub place_of_supply: String,
+    #[serde(default)]
+    pub pos_item_id: i64,
+    /// Leads are created unassigned when this is unset.
+    #[serde(default)]
+    pub lead_owner_uuid: Option<String>,
+    #[serde(default)]
+    pub notification_email: Option<String>,
+    #[serde(default)]
+    pub notification_mobile: Option<String>,
+    /// The POS node bills `place_of_supply` from another state, so IGST applies.
+    #[serde(default)]
+    pub inter_state_supply: bool,
+    #[serde(default)]
+    pub tax_inclusive_prices: bool,
+}
+
+impl Fixtures {
+    /// `POS_NODE_ID`, `PLACE_OF_SUPPLY`, `POS_ITEM_ID` and `LEAD_OWNER_UUID` win over the
+    /// YAML values.
+    pub fn apply_env(&mut self) {
+        if let Ok(id) = env::var("POS_NODE_ID") {
+            self.pos_node_id = id;
+        }
+        if let Ok(id) = env::var("PLACE_OF_SUPPLY") {
+            self.place_of_supply = id;
+        }
+        if let Some(id) = env::var("POS_ITEM_ID").ok().and_then(|v| v.parse().ok()) {
+            self.pos_item_id = id;
+        }
+        if let Ok(uuid) = env::var("LEAD_OWNER_UUID") {
+            self.lead_owner_uuid = Some(uuid).filter(|uuid| !uuid.trim().is_empty());
+        }
+    }
+
+    /// Names the POS fixtures left empty; the backend rejects or misfiles requests built
+    /// from them.
+    pub fn missing(&self) -> Vec<&'static str> {
+        [
+            ("pos_node_id", self.pos_node_id.trim().is_empty()),
+            ("place_of_supply", self.place_of_supply.trim().is_empty()),
+            ("pos_item_id", self.pos_item_id <= 0),
+        ]
+        .into_iter()
+        .filter_map(|(name, missing)| missing.then_some(name))
+        .collect()
+    }
+
+    /// For suites that build POS requests: fails naming the fixtures they would send empty.
+    pub fn require_pos(&self) -> anyhow::Result<()> {
+        let missing = self.missing();
+        anyhow::ensure!(
+            missing.is_empty(),
+            "Missing fixtures: {} (set them under `fixtures` in config/<env>.yaml or with \
+             POS_NODE_ID, PLACE_OF_SUPPLY and POS_ITEM_ID)",
+            missing.join(", ")
+        );
+        Ok(())
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct WaitSettings {
+    pub timeout_ms: u64,
+    pub page_load_timeout_ms: u64,
+    pub poll_interval_ms: u64,
+    pub network_quiet_ms: u64,
+}
+
+impl Default for WaitSettings {
+    fn default() -> Self {
+        Self {
+            timeout_ms: 10_000,
+            page_load_timeout_ms: 30_000,
+            poll_interval_ms: 250,
+            network_quiet_ms: 500,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub struct WindowSize {
+    pub width: u32,
+    pub height: u32,
+}
+
+impl WindowSize {
+    /// Parses `1280x800` (or `1280,800`).
+    pub fn parse(value: &str) -> Option<Self> {
+        let (width, height) = value.split_once(['x', ','])?;
+        Some(Self {
+            width: width.trim().parse().ok()?,
+            height: height.trim().parse().ok()?,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct BrowserConfig {
+    pub name: String,
+    pub headless: bool,
+    pub window_size: Option<WindowSize>,
+    /// Preset from `driver::device_profile` (e.g. `pixel-7`) or a Chrome DevTools device name.
+    pub device: Option<String>,
+    pub locale: Option<String>,
+    pub timezone: Option<String>,
+    pub download_dir: Option<String>,
+    pub extra_args: Vec<String>,
+}
+
+impl Default for BrowserConfig {
+    fn default() -> Self {
+        Self {
+            name: "chrome".to_string(),
+            headless: false,
+            window_size: None,
+            device: None,
+            locale: None,
+            timezone: None,
+            download_dir: None,
+            extra_args: Vec::new(),
+        }
+    }
+}
+
+impl BrowserConfig {
+    /// Environment variables win over the YAML values.
+    pub fn apply_env(&mut self) {
+        if let Ok(name) = env::var("BROWSER") {
+            self.name = name;
+        }
+        if let Ok(headless) = env::var("HEADLESS") {
+            self.headless = matches!(headless.as_str(), "1" | "true" | "yes");
+        }
+        if let Some(size) = env::var("WINDOW_SIZE")
+            .ok()
+            .and_then(|v| WindowSize::parse(&v))
+        {
+            self.window_size = Some(size);
+        }
+        if let Ok(device) = env::var("DEVICE") {
+            self.device = Some(device);
+        }
+        if let Ok(locale) = env::var("BROWSER_LOCALE") {
+            self.locale = Some(locale);
+        }
+        if let Ok(timezone) = env::var("BROWSER_TIMEZONE") {
+            self.timezone = Some(timezone);
+        }
+        if let Ok(dir) = env::var("DOWNLOAD_DIR") {
+            self.download_dir = Some(dir);
+        }
+        if let Ok(args) = env::var("BROWSER_ARGS") {
+            self.extra_args
+                .extend(args.split_whitespace().map(str::to_string));
+        }
+    }
+
+    /// CLI overrides win over both the YAML and the environment.
+    pub fn apply_overrides(&mut self, overrides: &ConfigOverrides) {
+        if let Some(name) = &overrides.browser {
+            self.name = name.clone();
+        }
+        if let Some(headless) = overrides.headless {
+            self.headless = headless;
+        }
+        if let Some(size) = overrides.window_size {
+            self.window_size = Some(size);
+        }
+        if let Some(device) = &overrides.device {
+            self.device = Some(device.clone());
+        }
+        if let Some(locale) = &overrides.locale {
+            self.locale = Some(locale.clone());
+        }
+        if let Some(timezone) = &overrides.timezone {
+            self.timezone = Some(timezone.clone());
+        }
+        if let Some(dir) = &overrides.download_dir {
+            self.download_dir = Some(dir.clone());
+        }
+    }
+}
+
+/// Settings the runner's CLI flags override, handed to the loader through `init_config`.
+#[derive(Debug, Clone, Default)]
+pub struct ConfigOverrides {
+    pub browser: Option<String>,
+    pub headless: Option<bool>,
+    pub window_size: Option<WindowSize>,
+    pub device: Option<String>,
+    pub locale: Option<String>,
+    pub timezone: Option<String>,
+    pub download_dir: Option<String>,
+    pub update_baselines: bool,
+}
+
+// `browser: chrome` is still accepted as shorthand for `browser: { name: chrome }`.
+fn browser_config<'de, D>(deserializer: D) -> Result<BrowserConfig, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Setting {
+        Name(String),
+        Full(BrowserConfig),
+    }
+
+    Ok(match Setting::deserialize(deserializer)? {
+        Setting::Name(name) => BrowserConfig {
+            name,
+            ..BrowserConfig::default()
+        },
+        Setting::Full(config) => config,
+    })
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct SessionSettings {
+    pub cache_dir: String,
+    pub max_age_hours: u64,
+    /// Authenticated GET endpoint (relative to `api_base_url`) used to check a restored session.
+    pub probe_path: String,
+    /// Storage key holding the bearer token; when unset the first JWT-looking value is used.
+    pub token_key: Option<String>,
+    /// Cookie set to the bearer token after an API login, for frontends that read it there.
+    pub token_cookie: Option<String>,
+    /// Extra localStorage entries written after an API login; `{token}` and `{email}` are
+    /// substituted.
+    pub inject_local_storage: BTreeMap<String, String>,
+}
+
+impl Default for SessionSettings {
+    fn default() -> Self {
+        Self {
+            cache_dir: ".cache/sessions".to_string(),
+            max_age_hours: 12,
+            probe_path: "api/v1/admin/invoice-listing-wms?page=1&limit=1".to_string(),
+            token_key: None,
+            token_cookie: None,
+            inject_local_storage: BTreeMap::new(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct A11ySettings {
+    /// Path to `axe.min.js`. The E2E suite fetches it with `crates/e2e_tests/assets/fetch-axe.sh`
+    /// when missing and stops before the first scenario if that fails.
+    pub script: String,
+    /// Lowest impact (`minor`, `moderate`, `serious`, `critical`) that fails an audit.
+    pub fail_on: String,
+    /// axe-core tags to run, e.g. `wcag2a`; empty runs every rule.
+    pub tags: Vec<String>,
+    pub disabled_rules: Vec<String>,
+}
+
+impl Default for A11ySettings {
+    fn default() -> Self {
+        Self {
+            script: "crates/e2e_tests/assets/axe.min.js".to_string(),
+            fail_on: "serious".to_string(),
+            tags: vec!["wcag2a".to_string(), "wcag2aa".to_string()],
+            disabled_rules: Vec::new(),
+        }
+    }
+}
+
+/// Login credentials for one account. The default persona is the `WMS_*` account; any
+/// other name `x` reads `X_EMAIL`, `X_PASSWORD`, `X_PHONE` and `X_OTP`.
+#[derive(Debug, Clone)]
+pub struct Persona {
+    pub name: String,
+    pub email: String,
+    pub password: String,
+    pub mobile_number: String,
+    pub otp: String,
+    pub country_code: String,
+}
+
+impl Persona {
+    pub fn default_user() -> Self {
+        let cfg = get_config();
+        Self {
+            name: "default".to_string(),
+            email: cfg.email.clone(),
+            password: cfg.password.clone(),
+            mobile_number: cfg.mobile_number.clone(),
+            otp: cfg.otp.clone(),
+            country_code: cfg.country_code.clone(),
+        }
+    }
+
+    /// Login fields left empty, e.g. an unset `WMS_OTP`.
+    pub fn missing_credentials(&self) -> Vec<&'static str> {
+        [
+            ("email", &self.email),
+            ("password", &self.password),
+            ("mobile number", &self.mobile_number),
+            ("OTP", &self.otp),
+        ]
+        .into_iter()
+        .filter_map(|(name, value)| value.trim().is_empty().then_some(name))
+        .collect()
+    }
+
+    pub fn named(name: &str) -> anyhow::Result<Self> {
+        if name == "default" {
+            return Ok(Self::default_user());
+        }
+        let prefix = name.to_ascii_uppercase();
+        let var = |suffix: &str| {
+            let key = format!("{}_{}", prefix, suffix);
+            env::var(&key).map_err(|_| anyhow::anyhow!("{} not set for persona '{}'", key, name))
+        };
+        Ok(Self {
+            name: name.to_string(),
+            email: var("EMAIL")?,
+            password: var("PASSWORD")?,
+            mobile_number: var("PHONE")?,
+            otp: var("OTP")?,
+            country_code: get_config().country_code.clone(),
+        })
+    }
+}
+
+fn env_flag(key: &str) -> bool {
+    matches!(env::var(key).as_deref(), Ok("1" | "true" | "yes"))
+}
+
+fn env_millis(key: &str) -> Option<u64> {
+    env::var(key).ok().and_then(|v| v.parse().ok())
+}
+
+static OVERRIDES: OnceCell<ConfigOverrides> = OnceCell::new();
+
 static CONFIG: Lazy<AppConfig> = Lazy::new(|| {
     dotenv().ok();
     let env = env::var("APP_ENV").unwrap_or_else(|_| "dev".to_string());
@@ -32,9 +392,33 @@ static CONFIG: Lazy<AppConfig> = Lazy::new(|| {
     config.otp = env::var("WMS_OTP").expect("WMS_OTP not set");
     config.elder_number = env::var("ELDER_NUMBER").expect("ELDER_NUMBER not set");
 
+    let overrides = OVERRIDES.get_or_init(ConfigOverrides::default);
+    config.browser.apply_env();
+    config.browser.apply_overrides(overrides);
+    config.update_baselines = overrides.update_baselines || env_flag("UPDATE_BASELINES");
+    config.fixtures.apply_env();
+    if let Ok(impact) = env::var("A11Y_FAIL_ON") {
+        config.a11y.fail_on = impact;
+    }
+
+    if let Some(ms) = env_millis("WAIT_TIMEOUT_MS") {
+        config.wait.timeout_ms = ms;
+    }
+    if let Some(ms) = env_millis("WAIT_PAGE_LOAD_TIMEOUT_MS") {
+        config.wait.page_load_timeout_ms = ms;
+    }
+
     config
 });
 
+/// Hands the CLI `overrides` to the loader. Must run before the first `get_config`.
+pub fn init_config(overrides: ConfigOverrides) {
+    assert!(
+        Lazy::get(&CONFIG).is_none() && OVERRIDES.set(overrides).is_ok(),
+        "init_config called after the config was loaded"
+    );
+}
+
 pub fn get_config() -> &'static AppConfig {
     &CONFIG
 }
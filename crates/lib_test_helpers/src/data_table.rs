@@ -0,0 +1,98 @@
+use anyhow::{anyhow, bail, Context, Result};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DataRow {
+    pub index: usize,
+    pub values: Vec<(String, String)>,
+}
+
+impl DataRow {
+    pub fn get(&self, column: &str) -> Option<&str> {
+        self.values
+            .iter()
+            .find(|(key, _)| key == column)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn require(&self, column: &str) -> Result<&str> {
+        self.get(column)
+            .ok_or_else(|| anyhow!("Row {} has no column '{}'", self.index + 1, column))
+    }
+
+    pub fn describe(&self) -> String {
+        self.values
+            .iter()
+            .map(|(key, value)| format!("{}={}", key, value))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    pub fn to_map(&self) -> BTreeMap<String, String> {
+        self.values.iter().cloned().collect()
+    }
+}
+
+pub fn load_table(path: impl AsRef<Path>) -> Result<Vec<DataRow>> {
+    let path = path.as_ref();
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("csv") => load_csv(path),
+        Some("json") => load_json(path),
+        _ => bail!(
+            "Unsupported data table format: {} (expected .csv or .json)",
+            path.display()
+        ),
+    }
+}
+
+fn load_csv(path: &Path) -> Result<Vec<DataRow>> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_path(path)
+        .with_context(|| format!("Failed to open data table {}", path.display()))?;
+    let headers = reader.headers()?.clone();
+
+    reader
+        .records()
+        .enumerate()
+        .map(|(index, record)| {
+            let record = record
+                .with_context(|| format!("Invalid row {} in {}", index + 1, path.display()))?;
+            let values = headers
+                .iter()
+                .zip(record.iter())
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect();
+            Ok(DataRow { index, values })
+        })
+        .collect()
+}
+
+fn load_json(path: &Path) -> Result<Vec<DataRow>> {
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read data table {}", path.display()))?;
+    let rows: Vec<serde_json::Map<String, Value>> = serde_json::from_str(&content)
+        .with_context(|| format!("{} must be a JSON array of objects", path.display()))?;
+
+    Ok(rows
+        .into_iter()
+        .enumerate()
+        .map(|(index, row)| DataRow {
+            index,
+            values: row
+                .into_iter()
+                .map(|(key, value)| {
+                    let value = value
+                        .as_str()
+                        .map(|s| s.to_string())
+                        .unwrap_or_else(|| value.to_string());
+                    (key, value)
+                })
+                .collect(),
+        })
+        .collect())
+}
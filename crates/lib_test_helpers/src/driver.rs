@@ -1,28 +1,218 @@
-use thirtyfour::prelude::*;
-use dotenvy::dotenv;
+use crate::config::{get_config, BrowserConfig, WindowSize};
+use crate::driver_service::active_url;
+use serde_json::{json, Map, Value};
 use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use thirtyfour::common::capabilities::firefox::FirefoxPreferences;
+use thirtyfour::extensions::cdp::ChromeDevTools;
+use thirtyfour::prelude::*;
+use thirtyfour::{ChromeCapabilities, FirefoxCapabilities};
 
-pub async fn global_driver() -> WebDriverResult<WebDriver> {
-    dotenv().ok();
-    let browser = env::var("BROWSER").unwrap_or_else(|_| "chrome".to_string());
-    let server_url = env::var("WEBDRIVER_URL").unwrap_or_else(|_| "http://localhost:9515".to_string());
+const DOWNLOAD_MIME_TYPES: &str = "application/pdf,application/octet-stream,text/csv";
+
+#[derive(Debug, Clone, Copy)]
+pub struct DeviceProfile {
+    pub width: u32,
+    pub height: u32,
+    pub pixel_ratio: f64,
+    pub user_agent: &'static str,
+}
 
-    let driver = match browser.as_str() {
+pub fn device_profile(name: &str) -> Option<DeviceProfile> {
+    let profile = match name.to_ascii_lowercase().as_str() {
+        "iphone-14" => DeviceProfile {
+            width: 390,
+            height: 844,
+            pixel_ratio: 3.0,
+            user_agent: "Mozilla/5.0 (iPhone; CPU iPhone OS 16_0 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/16.0 Mobile/15E148 Safari/604.1",
+        },
+        "pixel-7" => DeviceProfile {
+            width: 412,
+            height: 915,
+            pixel_ratio: 2.625,
+            user_agent: "Mozilla/5.0 (Linux; Android 13; Pixel 7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/116.0.0.0 Mobile Safari/537.36",
+        },
+        "ipad-air" => DeviceProfile {
+            width: 820,
+            height: 1180,
+            pixel_ratio: 2.0,
+            user_agent: "Mozilla/5.0 (iPad; CPU OS 16_0 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/16.0 Mobile/15E148 Safari/604.1",
+        },
+        _ => return None,
+    };
+    Some(profile)
+}
+
+fn server_url() -> String {
+    env::var("WEBDRIVER_URL")
+        .ok()
+        .or_else(active_url)
+        .unwrap_or_else(|| "http://localhost:9515".to_string())
+}
+
+fn download_dir(browser: &BrowserConfig) -> std::io::Result<Option<PathBuf>> {
+    let Some(dir) = &browser.download_dir else {
+        return Ok(None);
+    };
+    fs::create_dir_all(dir)?;
+    // Browsers silently ignore relative download paths.
+    Ok(Some(fs::canonicalize(dir)?))
+}
+
+#[allow(clippy::result_large_err)]
+fn chrome_caps(
+    browser: &BrowserConfig,
+    profile: Option<(&Path, u16)>,
+) -> WebDriverResult<ChromeCapabilities> {
+    let mut caps = DesiredCapabilities::chrome();
+    caps.add_arg("--no-sandbox")?;
+    caps.add_arg("--disable-dev-shm-usage")?;
+    caps.add_arg("--disable-gpu")?;
+    caps.add_arg("--disable-extensions")?;
+    caps.add_arg("--disable-infobars")?;
+    if browser.headless {
+        caps.add_arg("--headless=new")?;
+    }
+    if let Some((profile_dir, debugging_port)) = profile {
+        caps.add_arg(&format!("--user-data-dir={}", profile_dir.display()))?;
+        caps.add_arg(&format!("--remote-debugging-port={}", debugging_port))?;
+    }
+    if let Some(WindowSize { width, height }) = browser.window_size {
+        caps.add_arg(&format!("--window-size={},{}", width, height))?;
+    }
+
+    if let Some(device) = &browser.device {
+        let emulation = match device_profile(device) {
+            Some(p) => json!({
+                "deviceMetrics": { "width": p.width, "height": p.height, "pixelRatio": p.pixel_ratio },
+                "userAgent": p.user_agent,
+            }),
+            None => json!({ "deviceName": device }),
+        };
+        caps.add_experimental_option("mobileEmulation", emulation)?;
+    }
+
+    let mut prefs = Map::new();
+    if let Some(locale) = &browser.locale {
+        caps.add_arg(&format!("--lang={}", locale))?;
+        prefs.insert("intl.accept_languages".into(), json!(locale));
+    }
+    if let Some(dir) = download_dir(browser)? {
+        prefs.insert("download.default_directory".into(), json!(dir));
+        prefs.insert("download.prompt_for_download".into(), json!(false));
+        prefs.insert("download.directory_upgrade".into(), json!(true));
+        prefs.insert("plugins.always_open_pdf_externally".into(), json!(true));
+    }
+    if !prefs.is_empty() {
+        caps.add_experimental_option("prefs", Value::Object(prefs))?;
+    }
+
+    for arg in &browser.extra_args {
+        caps.add_arg(arg)?;
+    }
+    Ok(caps)
+}
+
+#[allow(clippy::result_large_err)]
+fn firefox_caps(
+    browser: &BrowserConfig,
+    profile_dir: Option<&Path>,
+) -> WebDriverResult<FirefoxCapabilities> {
+    let mut caps = DesiredCapabilities::firefox();
+    let mut prefs = FirefoxPreferences::new();
+    if browser.headless {
+        caps.add_arg("-headless")?;
+    }
+    if let Some(profile_dir) = profile_dir {
+        caps.add_arg("-profile")?;
+        caps.add_arg(&profile_dir.display().to_string())?;
+    }
+
+    let device = browser
+        .device
+        .as_deref()
+        .map(|name| (name, device_profile(name)));
+    let size = match device {
+        Some((_, Some(p))) => {
+            prefs.set_user_agent(p.user_agent.to_string())?;
+            Some(WindowSize {
+                width: p.width,
+                height: p.height,
+            })
+        }
+        Some((name, None)) => {
+            eprintln!("⚠️ Unknown device '{}' ignored for Firefox", name);
+            browser.window_size
+        }
+        None => browser.window_size,
+    };
+    if let Some(WindowSize { width, height }) = size {
+        caps.add_arg(&format!("--width={}", width))?;
+        caps.add_arg(&format!("--height={}", height))?;
+    }
+
+    if let Some(locale) = &browser.locale {
+        prefs.set("intl.accept_languages", locale)?;
+        prefs.set("intl.locale.requested", locale)?;
+    }
+    if let Some(dir) = download_dir(browser)? {
+        prefs.set("browser.download.folderList", 2)?;
+        prefs.set("browser.download.dir", dir.display().to_string())?;
+        prefs.set("browser.download.useDownloadDir", true)?;
+        prefs.set(
+            "browser.helperApps.neverAsk.saveToDisk",
+            DOWNLOAD_MIME_TYPES,
+        )?;
+        prefs.set("pdfjs.disabled", true)?;
+    }
+    caps.set_preferences(prefs)?;
+
+    for arg in &browser.extra_args {
+        caps.add_arg(arg)?;
+    }
+    Ok(caps)
+}
+
+pub async fn new_driver(
+    browser: &BrowserConfig,
+    profile: Option<(&Path, u16)>,
+) -> WebDriverResult<WebDriver> {
+    let server_url = server_url();
+
+    let driver = match browser.name.as_str() {
         "firefox" => {
-            let caps = DesiredCapabilities::firefox();
+            let caps = firefox_caps(browser, profile.map(|(dir, _)| dir))?;
             WebDriver::new(&server_url, caps).await?
         }
         _ => {
-            let mut caps = DesiredCapabilities::chrome();
-            caps.add_arg("--no-sandbox")?;
-            caps.add_arg("--disable-dev-shm-usage")?;
-            caps.add_arg("--disable-gpu")?;
-            caps.add_arg("--disable-extensions")?;
-            caps.add_arg("--disable-infobars")?;
-            caps.add_arg("--remote-debugging-port=9222")?;
-            WebDriver::new(&server_url, caps).await?
+            let caps = chrome_caps(browser, profile)?;
+            let driver = WebDriver::new(&server_url, caps).await?;
+            // Firefox follows the driver process's TZ instead (see `DriverServiceBuilder::timezone`).
+            if let Some(timezone) = &browser.timezone {
+                ChromeDevTools::new(driver.handle.clone())
+                    .execute_cdp_with_params(
+                        "Emulation.setTimezoneOverride",
+                        json!({ "timezoneId": timezone }),
+                    )
+                    .await?;
+            }
+            driver
         }
     };
 
     Ok(driver)
 }
+
+pub async fn global_driver() -> WebDriverResult<WebDriver> {
+    new_driver(&get_config().browser, None).await
+}
+
+/// Browser with its own profile directory and DevTools port, so several can run side by
+/// side without sharing cookies, storage or a debugging endpoint.
+pub async fn isolated_driver(
+    profile_dir: &Path,
+    debugging_port: u16,
+) -> WebDriverResult<WebDriver> {
+    new_driver(&get_config().browser, Some((profile_dir, debugging_port))).await
+}
@@ -0,0 +1,317 @@
+use anyhow::{bail, Context, Result};
+use once_cell::sync::Lazy;
+use std::env;
+use std::fs::{self, File};
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::sync::{Arc, Mutex, Once, RwLock};
+use std::thread;
+use std::time::{Duration, Instant};
+use wait_timeout::ChildExt;
+
+const DEFAULT_LOG_DIR: &str = "crates/e2e_tests/reports/logs";
+const STARTUP_TIMEOUT: Duration = Duration::from_secs(15);
+const STATUS_POLL_INTERVAL: Duration = Duration::from_millis(100);
+/// How long the driver gets to close its browsers after SIGTERM before the group is killed.
+const SHUTDOWN_GRACE: Duration = Duration::from_secs(3);
+
+static ACTIVE_URL: RwLock<Option<String>> = RwLock::new(None);
+static RUNNING: Lazy<Mutex<Vec<Arc<Mutex<Child>>>>> = Lazy::new(|| Mutex::new(Vec::new()));
+static CTRL_C: Once = Once::new();
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DriverKind {
+    Chrome,
+    Firefox,
+}
+
+impl DriverKind {
+    pub fn from_env() -> Self {
+        Self::from_name(&env::var("BROWSER").unwrap_or_default())
+    }
+
+    pub fn from_name(name: &str) -> Self {
+        match name {
+            "firefox" => DriverKind::Firefox,
+            _ => DriverKind::Chrome,
+        }
+    }
+
+    pub fn binary(&self) -> &'static str {
+        match self {
+            DriverKind::Chrome => "chromedriver",
+            DriverKind::Firefox => "geckodriver",
+        }
+    }
+
+    fn port_args(&self, port: u16) -> Vec<String> {
+        match self {
+            DriverKind::Chrome => vec![format!("--port={}", port)],
+            DriverKind::Firefox => vec!["--port".to_string(), port.to_string()],
+        }
+    }
+}
+
+/// URL of the driver started by the most recent `DriverService`, if it is still running.
+pub fn active_url() -> Option<String> {
+    ACTIVE_URL.read().ok().and_then(|url| url.clone())
+}
+
+pub struct DriverServiceBuilder {
+    kind: DriverKind,
+    binary: Option<PathBuf>,
+    port: Option<u16>,
+    log_dir: PathBuf,
+    startup_timeout: Duration,
+    timezone: Option<String>,
+}
+
+impl DriverServiceBuilder {
+    pub fn binary(mut self, binary: impl Into<PathBuf>) -> Self {
+        self.binary = Some(binary.into());
+        self
+    }
+
+    pub fn port(mut self, port: u16) -> Self {
+        self.port = Some(port);
+        self
+    }
+
+    pub fn log_dir(mut self, log_dir: impl Into<PathBuf>) -> Self {
+        self.log_dir = log_dir.into();
+        self
+    }
+
+    pub fn startup_timeout(mut self, timeout: Duration) -> Self {
+        self.startup_timeout = timeout;
+        self
+    }
+
+    /// Sets `TZ` for the driver and the browsers it launches.
+    pub fn timezone(mut self, timezone: impl Into<String>) -> Self {
+        self.timezone = Some(timezone.into());
+        self
+    }
+
+    pub fn start(self) -> Result<DriverService> {
+        install_ctrl_c_handler();
+
+        let port = match self.port {
+            Some(port) => port,
+            None => free_port()?,
+        };
+        let binary = self
+            .binary
+            .unwrap_or_else(|| PathBuf::from(self.kind.binary()));
+
+        fs::create_dir_all(&self.log_dir)?;
+        let log_path = self
+            .log_dir
+            .join(format!("{}-{}.log", self.kind.binary(), port));
+        let log = File::create(&log_path)
+            .with_context(|| format!("Failed to create {}", log_path.display()))?;
+
+        let mut command = Command::new(&binary);
+        command
+            .args(self.kind.port_args(port))
+            .stdout(Stdio::from(log.try_clone()?))
+            .stderr(Stdio::from(log))
+            .stdin(Stdio::null());
+        if let Some(timezone) = &self.timezone {
+            command.env("TZ", timezone);
+        }
+        // Its own process group, so Ctrl-C reaches only us and shutdown can signal the
+        // browsers along with the driver.
+        #[cfg(unix)]
+        std::os::unix::process::CommandExt::process_group(&mut command, 0);
+        let child = command
+            .spawn()
+            .with_context(|| format!("Failed to start {}", binary.display()))?;
+
+        let child = Arc::new(Mutex::new(child));
+        if let Ok(mut running) = RUNNING.lock() {
+            running.push(child.clone());
+        }
+
+        let service = DriverService {
+            kind: self.kind,
+            port,
+            log_path,
+            child,
+        };
+        service.wait_until_ready(self.startup_timeout)?;
+
+        if let Ok(mut url) = ACTIVE_URL.write() {
+            *url = Some(service.url());
+        }
+        println!(
+            "✅ {} started on port {} (log: {})",
+            service.kind.binary(),
+            port,
+            service.log_path.display()
+        );
+        Ok(service)
+    }
+}
+
+pub struct DriverService {
+    kind: DriverKind,
+    port: u16,
+    log_path: PathBuf,
+    child: Arc<Mutex<Child>>,
+}
+
+impl DriverService {
+    pub fn builder(kind: DriverKind) -> DriverServiceBuilder {
+        DriverServiceBuilder {
+            kind,
+            binary: None,
+            port: None,
+            log_dir: PathBuf::from(DEFAULT_LOG_DIR),
+            startup_timeout: STARTUP_TIMEOUT,
+            timezone: None,
+        }
+    }
+
+    pub fn start(kind: DriverKind) -> Result<Self> {
+        Self::builder(kind).start()
+    }
+
+    pub fn kind(&self) -> DriverKind {
+        self.kind
+    }
+
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    pub fn url(&self) -> String {
+        format!("http://127.0.0.1:{}", self.port)
+    }
+
+    pub fn log_path(&self) -> &Path {
+        &self.log_path
+    }
+
+    fn wait_until_ready(&self, timeout: Duration) -> Result<()> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            if let Ok(mut child) = self.child.lock() {
+                if let Some(status) = child.try_wait()? {
+                    bail!(
+                        "{} exited with {} before becoming ready:\n{}",
+                        self.kind.binary(),
+                        status,
+                        log_tail(&self.log_path)
+                    );
+                }
+            }
+            if status_ready(self.port) {
+                return Ok(());
+            }
+            if Instant::now() >= deadline {
+                self.stop();
+                bail!(
+                    "{} did not report ready on port {} within {:?}:\n{}",
+                    self.kind.binary(),
+                    self.port,
+                    timeout,
+                    log_tail(&self.log_path)
+                );
+            }
+            thread::sleep(STATUS_POLL_INTERVAL);
+        }
+    }
+
+    pub fn stop(&self) {
+        if let Ok(mut child) = self.child.lock() {
+            shut_down(&mut child);
+        }
+        if let Ok(mut running) = RUNNING.lock() {
+            running.retain(|c| !Arc::ptr_eq(c, &self.child));
+        }
+        if let Ok(mut url) = ACTIVE_URL.write() {
+            if url.as_deref() == Some(self.url().as_str()) {
+                *url = None;
+            }
+        }
+    }
+}
+
+impl Drop for DriverService {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+pub(crate) fn free_port() -> Result<u16> {
+    let listener = TcpListener::bind("127.0.0.1:0").context("No free local port available")?;
+    Ok(listener.local_addr()?.port())
+}
+
+// Speaks just enough HTTP to read `/status` without pulling an HTTP client into this crate.
+fn status_ready(port: u16) -> bool {
+    let Ok(mut stream) = TcpStream::connect(("127.0.0.1", port)) else {
+        return false;
+    };
+    let _ = stream.set_read_timeout(Some(Duration::from_secs(1)));
+    let request = format!(
+        "GET /status HTTP/1.1\r\nHost: 127.0.0.1:{}\r\nConnection: close\r\n\r\n",
+        port
+    );
+    if stream.write_all(request.as_bytes()).is_err() {
+        return false;
+    }
+    let mut response = String::new();
+    let _ = stream.read_to_string(&mut response);
+    let ok = response.split_whitespace().nth(1) == Some("200");
+    ok && response.replace(' ', "").contains("\"ready\":true")
+}
+
+fn log_tail(path: &Path) -> String {
+    let log = fs::read_to_string(path).unwrap_or_default();
+    let lines: Vec<&str> = log.lines().collect();
+    lines[lines.len().saturating_sub(20)..].join("\n")
+}
+
+fn install_ctrl_c_handler() {
+    CTRL_C.call_once(|| {
+        let result = ctrlc::set_handler(|| {
+            if let Ok(running) = RUNNING.lock() {
+                for child in running.iter() {
+                    if let Ok(mut child) = child.lock() {
+                        shut_down(&mut child);
+                    }
+                }
+            }
+            std::process::exit(130);
+        });
+        if let Err(e) = result {
+            eprintln!("⚠️ Could not install Ctrl-C handler: {}", e);
+        }
+    });
+}
+
+/// Asks the driver's process group to exit with SIGTERM, then SIGKILLs whatever is left once
+/// `SHUTDOWN_GRACE` has passed.
+#[cfg(unix)]
+fn shut_down(child: &mut Child) {
+    if let Ok(Some(_)) = child.try_wait() {
+        return;
+    }
+    let group = -(child.id() as libc::pid_t);
+    // SAFETY: kill(2) only sends a signal, to the group the driver was spawned into.
+    unsafe { libc::kill(group, libc::SIGTERM) };
+    let _ = child.wait_timeout(SHUTDOWN_GRACE);
+    // Also reaches browsers the driver left behind when it exited in time.
+    unsafe { libc::kill(group, libc::SIGKILL) };
+    let _ = child.wait();
+}
+
+#[cfg(not(unix))]
+fn shut_down(child: &mut Child) {
+    let _ = child.kill();
+    let _ = child.wait();
+}
@@ -0,0 +1,379 @@
+use crate::seed::with_rng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const GSTIN_CHARSET: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+const VERHOEFF_D: [[u8; 10]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+    [1, 2, 3, 4, 0, 6, 7, 8, 9, 5],
+    [2, 3, 4, 0, 1, 7, 8, 9, 5, 6],
+    [3, 4, 0, 1, 2, 8, 9, 5, 6, 7],
+    [4, 0, 1, 2, 3, 9, 5, 6, 7, 8],
+    [5, 9, 8, 7, 6, 0, 4, 3, 2, 1],
+    [6, 5, 9, 8, 7, 1, 0, 4, 3, 2],
+    [7, 6, 5, 9, 8, 2, 1, 0, 4, 3],
+    [8, 7, 6, 5, 9, 3, 2, 1, 0, 4],
+    [9, 8, 7, 6, 5, 4, 3, 2, 1, 0],
+];
+
+const VERHOEFF_P: [[u8; 10]; 8] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+    [1, 5, 7, 6, 2, 8, 3, 0, 9, 4],
+    [5, 8, 0, 3, 7, 9, 6, 1, 4, 2],
+    [8, 9, 1, 6, 0, 4, 3, 5, 2, 7],
+    [9, 4, 5, 3, 1, 2, 6, 8, 7, 0],
+    [4, 2, 8, 6, 5, 7, 3, 9, 0, 1],
+    [2, 7, 9, 3, 8, 0, 6, 4, 1, 5],
+    [7, 0, 4, 6, 9, 1, 3, 2, 5, 8],
+];
+
+const VERHOEFF_INV: [u8; 10] = [0, 4, 3, 2, 1, 5, 6, 7, 8, 9];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndianState {
+    pub code: &'static str,
+    pub name: &'static str,
+    pub gst_code: &'static str,
+}
+
+impl IndianState {
+    const fn new(code: &'static str, name: &'static str, gst_code: &'static str) -> Self {
+        Self {
+            code,
+            name,
+            gst_code,
+        }
+    }
+
+    /// Label used by the WMS place-of-supply dropdown, e.g. `HR,Haryana`.
+    pub fn label(&self) -> String {
+        format!("{},{}", self.code, self.name)
+    }
+}
+
+pub const STATES: &[IndianState] = &[
+    IndianState::new("JK", "Jammu and Kashmir", "01"),
+    IndianState::new("HP", "Himachal Pradesh", "02"),
+    IndianState::new("PB", "Punjab", "03"),
+    IndianState::new("CH", "Chandigarh", "04"),
+    IndianState::new("UK", "Uttarakhand", "05"),
+    IndianState::new("HR", "Haryana", "06"),
+    IndianState::new("DL", "Delhi", "07"),
+    IndianState::new("RJ", "Rajasthan", "08"),
+    IndianState::new("UP", "Uttar Pradesh", "09"),
+    IndianState::new("BR", "Bihar", "10"),
+    IndianState::new("SK", "Sikkim", "11"),
+    IndianState::new("AR", "Arunachal Pradesh", "12"),
+    IndianState::new("NL", "Nagaland", "13"),
+    IndianState::new("MN", "Manipur", "14"),
+    IndianState::new("MZ", "Mizoram", "15"),
+    IndianState::new("TR", "Tripura", "16"),
+    IndianState::new("ML", "Meghalaya", "17"),
+    IndianState::new("AS", "Assam", "18"),
+    IndianState::new("WB", "West Bengal", "19"),
+    IndianState::new("JH", "Jharkhand", "20"),
+    IndianState::new("OD", "Odisha", "21"),
+    IndianState::new("CG", "Chhattisgarh", "22"),
+    IndianState::new("MP", "Madhya Pradesh", "23"),
+    IndianState::new("GJ", "Gujarat", "24"),
+    IndianState::new("DN", "Dadra and Nagar Haveli and Daman and Diu", "26"),
+    IndianState::new("MH", "Maharashtra", "27"),
+    IndianState::new("KA", "Karnataka", "29"),
+    IndianState::new("GA", "Goa", "30"),
+    IndianState::new("LD", "Lakshadweep", "31"),
+    IndianState::new("KL", "Kerala", "32"),
+    IndianState::new("TN", "Tamil Nadu", "33"),
+    IndianState::new("PY", "Puducherry", "34"),
+    IndianState::new("AN", "Andaman and Nicobar Islands", "35"),
+    IndianState::new("TS", "Telangana", "36"),
+    IndianState::new("AP", "Andhra Pradesh", "37"),
+    IndianState::new("LA", "Ladakh", "38"),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidValue {
+    pub reason: &'static str,
+    pub value: String,
+}
+
+impl InvalidValue {
+    fn new(reason: &'static str, value: impl Into<String>) -> Self {
+        Self {
+            reason,
+            value: value.into(),
+        }
+    }
+}
+
+pub struct IndiaDataFactory;
+
+impl IndiaDataFactory {
+    pub fn mobile_number() -> String {
+        with_rng(|rng| {
+            let first = rng.gen_range(6..=9);
+            format!("{}{:09}", first, rng.gen_range(0..1_000_000_000u32))
+        })
+    }
+
+    pub fn mobile_number_with_country_code() -> String {
+        format!("+91{}", Self::mobile_number())
+    }
+
+    pub fn invalid_mobile_numbers() -> Vec<InvalidValue> {
+        let valid = Self::mobile_number();
+        let (bad_prefix, letter) =
+            with_rng(|rng| (rng.gen_range(0..=5), rng.gen_range(b'a'..=b'z')));
+        vec![
+            InvalidValue::new("starts with 0-5", format!("{}{}", bad_prefix, &valid[1..])),
+            InvalidValue::new("too short", &valid[..9]),
+            InvalidValue::new("too long", format!("{}{}", valid, bad_prefix)),
+            InvalidValue::new(
+                "contains a letter",
+                format!("{}{}", &valid[..9], letter as char),
+            ),
+            InvalidValue::new(
+                "contains spaces",
+                format!("{} {}", &valid[..5], &valid[5..]),
+            ),
+            InvalidValue::new("empty", ""),
+        ]
+    }
+
+    pub fn is_valid_mobile_number(value: &str) -> bool {
+        value.len() == 10
+            && value.bytes().all(|b| b.is_ascii_digit())
+            && matches!(value.as_bytes()[0], b'6'..=b'9')
+    }
+
+    pub fn pincode() -> String {
+        with_rng(|rng| format!("{}{:05}", rng.gen_range(1..=8), rng.gen_range(0..100_000)))
+    }
+
+    pub fn invalid_pincodes() -> Vec<InvalidValue> {
+        let valid = Self::pincode();
+        vec![
+            InvalidValue::new("starts with 0", format!("0{}", &valid[1..])),
+            InvalidValue::new("starts with 9", format!("9{}", &valid[1..])),
+            InvalidValue::new("too short", &valid[..5]),
+            InvalidValue::new("too long", format!("{}1", valid)),
+            InvalidValue::new("contains a letter", format!("{}A", &valid[..5])),
+            InvalidValue::new("empty", ""),
+        ]
+    }
+
+    pub fn is_valid_pincode(value: &str) -> bool {
+        value.len() == 6
+            && value.bytes().all(|b| b.is_ascii_digit())
+            && matches!(value.as_bytes()[0], b'1'..=b'8')
+    }
+
+    pub fn state() -> IndianState {
+        with_rng(|rng| *STATES.choose(rng).expect("STATES is not empty"))
+    }
+
+    pub fn state_by_code(code: &str) -> Option<IndianState> {
+        STATES
+            .iter()
+            .find(|state| state.code.eq_ignore_ascii_case(code))
+            .copied()
+    }
+
+    pub fn invalid_state_codes() -> Vec<InvalidValue> {
+        vec![
+            InvalidValue::new("unknown code", "XX"),
+            InvalidValue::new("single letter", "H"),
+            InvalidValue::new("name without code", "Haryana"),
+            InvalidValue::new("unassigned GST code", "00"),
+            InvalidValue::new("empty", ""),
+        ]
+    }
+
+    pub fn pan() -> String {
+        with_rng(|rng| {
+            let letters: String = (0..3).map(|_| rng.gen_range(b'A'..=b'Z') as char).collect();
+            let holder_type = *b"PCHFATBLJG".choose(rng).expect("non-empty") as char;
+            let surname = rng.gen_range(b'A'..=b'Z') as char;
+            let digits = rng.gen_range(0..10_000);
+            let check = rng.gen_range(b'A'..=b'Z') as char;
+            format!(
+                "{}{}{}{:04}{}",
+                letters, holder_type, surname, digits, check
+            )
+        })
+    }
+
+    pub fn gstin() -> String {
+        Self::gstin_for_state(&Self::state())
+    }
+
+    pub fn gstin_for_state(state: &IndianState) -> String {
+        let entity = with_rng(|rng| GSTIN_CHARSET[rng.gen_range(1..GSTIN_CHARSET.len())] as char);
+        let base = format!("{}{}{}Z", state.gst_code, Self::pan(), entity);
+        let check = gstin_check_char(&base);
+        format!("{}{}", base, check)
+    }
+
+    pub fn invalid_gstins() -> Vec<InvalidValue> {
+        let valid = Self::gstin();
+        let check = valid.as_bytes()[14];
+        let wrong_check = GSTIN_CHARSET
+            .iter()
+            .find(|c| **c != check)
+            .copied()
+            .expect("charset has more than one char") as char;
+        // No state has GST code 00; the checksum is recomputed so only the code is wrong.
+        let unassigned_state = format!("00{}", &valid[2..14]);
+        let unassigned_state_check = gstin_check_char(&unassigned_state);
+        vec![
+            InvalidValue::new("wrong checksum", format!("{}{}", &valid[..14], wrong_check)),
+            InvalidValue::new(
+                "unknown state code",
+                format!("{}{}", unassigned_state, unassigned_state_check),
+            ),
+            InvalidValue::new("too short", &valid[..14]),
+            InvalidValue::new("lowercase", valid.to_lowercase()),
+            InvalidValue::new(
+                "missing Z marker",
+                format!("{}Y{}", &valid[..13], &valid[14..]),
+            ),
+            InvalidValue::new("empty", ""),
+        ]
+    }
+
+    pub fn is_valid_gstin(value: &str) -> bool {
+        value.len() == 15
+            && value.bytes().all(|b| GSTIN_CHARSET.contains(&b))
+            && STATES.iter().any(|state| state.gst_code == &value[..2])
+            && value.as_bytes()[13] == b'Z'
+            && gstin_check_char(&value[..14]) == value.as_bytes()[14] as char
+    }
+
+    pub fn aadhaar() -> String {
+        let base = with_rng(|rng| {
+            format!(
+                "{}{:010}",
+                rng.gen_range(2..=9),
+                rng.gen_range(0..10_000_000_000u64)
+            )
+        });
+        let check = verhoeff_check_digit(&base);
+        format!("{}{}", base, check)
+    }
+
+    pub fn invalid_aadhaars() -> Vec<InvalidValue> {
+        let valid = Self::aadhaar();
+        let last = valid.as_bytes()[11] - b'0';
+        vec![
+            InvalidValue::new(
+                "wrong checksum",
+                format!("{}{}", &valid[..11], (last + 1) % 10),
+            ),
+            InvalidValue::new("starts with 0", format!("0{}", &valid[1..])),
+            InvalidValue::new("starts with 1", format!("1{}", &valid[1..])),
+            InvalidValue::new("too short", &valid[..11]),
+            InvalidValue::new("contains a letter", format!("{}X", &valid[..11])),
+            InvalidValue::new("empty", ""),
+        ]
+    }
+
+    pub fn is_valid_aadhaar(value: &str) -> bool {
+        value.len() == 12
+            && value.bytes().all(|b| b.is_ascii_digit())
+            && matches!(value.as_bytes()[0], b'2'..=b'9')
+            && verhoeff_validate(value)
+    }
+}
+
+fn gstin_check_char(base: &str) -> char {
+    let sum: usize = base
+        .bytes()
+        .take(14)
+        .enumerate()
+        .map(|(i, b)| {
+            let value = GSTIN_CHARSET.iter().position(|c| *c == b).unwrap_or(0);
+            let product = value * if i % 2 == 0 { 1 } else { 2 };
+            product / 36 + product % 36
+        })
+        .sum();
+    GSTIN_CHARSET[(36 - sum % 36) % 36] as char
+}
+
+fn verhoeff_check_digit(digits: &str) -> u8 {
+    let c = digits.bytes().rev().enumerate().fold(0u8, |c, (i, b)| {
+        VERHOEFF_D[c as usize][VERHOEFF_P[(i + 1) % 8][(b - b'0') as usize] as usize]
+    });
+    VERHOEFF_INV[c as usize]
+}
+
+fn verhoeff_validate(digits: &str) -> bool {
+    digits.bytes().rev().enumerate().fold(0u8, |c, (i, b)| {
+        VERHOEFF_D[c as usize][VERHOEFF_P[i % 8][(b - b'0') as usize] as usize]
+    }) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gstin_check_char_matches_published_gstins() {
+        assert_eq!(gstin_check_char("27AAPFU0939F1Z"), 'V');
+        assert_eq!(gstin_check_char("29AAACH7409R1Z"), 'X');
+        assert!(IndiaDataFactory::is_valid_gstin("27AAPFU0939F1ZV"));
+        assert!(!IndiaDataFactory::is_valid_gstin("27AAPFU0939F1ZW"));
+    }
+
+    #[test]
+    fn generated_gstins_are_valid_and_invalid_ones_are_not() {
+        for _ in 0..50 {
+            assert!(IndiaDataFactory::is_valid_gstin(&IndiaDataFactory::gstin()));
+        }
+        for invalid in IndiaDataFactory::invalid_gstins() {
+            assert!(
+                !IndiaDataFactory::is_valid_gstin(&invalid.value),
+                "{} accepted: {}",
+                invalid.reason,
+                invalid.value
+            );
+        }
+    }
+
+    #[test]
+    fn verhoeff_matches_known_check_digits() {
+        assert_eq!(verhoeff_check_digit("236"), 3);
+        assert_eq!(verhoeff_check_digit("12345"), 1);
+        assert!(verhoeff_validate("2363"));
+        assert!(!verhoeff_validate("2364"));
+        assert!(IndiaDataFactory::is_valid_aadhaar("234123412346"));
+        assert!(!IndiaDataFactory::is_valid_aadhaar("234123412347"));
+    }
+
+    #[test]
+    fn generated_aadhaars_are_valid_and_invalid_ones_are_not() {
+        for _ in 0..50 {
+            assert!(IndiaDataFactory::is_valid_aadhaar(&IndiaDataFactory::aadhaar()));
+        }
+        for invalid in IndiaDataFactory::invalid_aadhaars() {
+            assert!(
+                !IndiaDataFactory::is_valid_aadhaar(&invalid.value),
+                "{} accepted: {}",
+                invalid.reason,
+                invalid.value
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_state_codes_match_no_state() {
+        for invalid in IndiaDataFactory::invalid_state_codes() {
+            assert!(
+                IndiaDataFactory::state_by_code(&invalid.value).is_none()
+                    && STATES.iter().all(|state| state.gst_code != invalid.value),
+                "{} is a real code: {}",
+                invalid.reason,
+                invalid.value
+            );
+        }
+    }
+}
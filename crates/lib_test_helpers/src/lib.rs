@@ -1,6 +1,12 @@
+pub mod browser_pool;
 pub mod config;
+pub mod data_table;
 pub mod driver;
-pub mod chrome_driver;
+pub mod driver_service;
+pub mod india_data;
+pub mod seed;
+pub mod reporting;
 pub mod session_file;
+pub mod wait;
 
 pub fn hello() { println!("lib_test_helpers ready"); }
@@ -1,6 +1,7 @@
 use super::reporter::Reporter;
-use super::types::{TestReport, TestStatus};
+use super::types::{A11yViolation, TestReport, TestResult, TestStatus};
 use anyhow::Result;
+use std::collections::BTreeMap;
 
 pub struct HtmlReporter;
 
@@ -32,7 +33,7 @@ impl Reporter for HtmlReporter {
 <head>
     <meta charset="UTF-8">
     <meta name="viewport" content="width=device-width, initial-scale=1.0">
-    <title>API Test Report - {suite_name}</title>
+    <title>Test Report - {suite_name}</title>
     <style>
         * {{
             margin: 0;
@@ -223,6 +224,22 @@ impl Reporter for HtmlReporter {
             color: #888;
             font-family: monospace;
         }}
+
+        tr.group-header {{
+            cursor: pointer;
+            background: rgba(102, 126, 234, 0.1);
+            font-weight: 600;
+        }}
+
+        tr.collapsed {{
+            display: none;
+        }}
+
+        .parameters {{
+            color: #a5b4fc;
+            font-family: monospace;
+            font-size: 0.75rem;
+        }}
         
         .error-details {{
             display: none;
@@ -278,6 +295,48 @@ impl Reporter for HtmlReporter {
             white-space: pre-wrap;
         }}
 
+        .attachments {{
+            margin-top: 1rem;
+            display: flex;
+            flex-wrap: wrap;
+            gap: 0.75rem;
+            align-items: flex-start;
+        }}
+
+        .attachments a {{
+            color: #a5b4fc;
+        }}
+
+        .attachments img {{
+            max-width: 320px;
+            border-radius: 8px;
+            border: 1px solid rgba(255, 255, 255, 0.1);
+        }}
+
+        .a11y-summary {{
+            margin-top: 2rem;
+        }}
+
+        .a11y-summary h2 {{
+            margin-bottom: 1rem;
+        }}
+
+        .a11y-page {{
+            margin-bottom: 1.5rem;
+        }}
+
+        .a11y-page h3 {{
+            font-size: 0.95rem;
+            margin-bottom: 0.5rem;
+            color: #a5b4fc;
+            font-family: monospace;
+            word-break: break-all;
+        }}
+
+        .impact-critical, .impact-serious {{ color: #ef4444; font-weight: 600; }}
+        .impact-moderate {{ color: #f59e0b; }}
+        .impact-minor {{ color: #888; }}
+
         footer {{
             margin-top: 2rem;
             text-align: center;
@@ -304,7 +363,7 @@ impl Reporter for HtmlReporter {
     <div class="container">
         <header>
             <h1>🔬 {suite_name}</h1>
-            <p class="timestamp">Executed on {execution_time} • Duration: {duration}</p>
+            <p class="timestamp">Executed on {execution_time} • Duration: {duration}{seed}</p>
         </header>
         
         <section class="summary-cards">
@@ -349,15 +408,15 @@ impl Reporter for HtmlReporter {
                 </tbody>
             </table>
         </section>
-        
+        {a11y}
         <footer>
-            <p>Generated by API Test Reporter • {execution_time}</p>
+            <p>Generated by QA Test Reporter • {execution_time}</p>
         </footer>
     </div>
     
     <script>
         function filterResults(filter) {{
-            const rows = document.querySelectorAll('tbody tr');
+            const rows = document.querySelectorAll('section.results-table tbody tr');
             const buttons = document.querySelectorAll('.filter-btn');
             
             buttons.forEach(btn => btn.classList.remove('active'));
@@ -374,6 +433,12 @@ impl Reporter for HtmlReporter {
             }});
         }}
         
+        function toggleGroup(id) {{
+            document.querySelectorAll('tr.' + id).forEach(row => {{
+                row.classList.toggle('collapsed');
+            }});
+        }}
+
         function toggleDetails(id) {{
             const details = document.getElementById(id);
             details.classList.toggle('show');
@@ -384,12 +449,17 @@ impl Reporter for HtmlReporter {
             suite_name = report.suite_name,
             execution_time = report.start_time.format("%Y-%m-%d %H:%M:%S UTC"),
             duration = report.format_duration(),
+            seed = report
+                .seed
+                .map(|seed| format!(" • Seed: {}", seed))
+                .unwrap_or_default(),
             total = report.total_tests,
             passed = report.passed,
             failed = report.failed,
             pass_rate = report.pass_rate,
             pass_color = pass_color,
             rows = self.generate_rows(report),
+            a11y = self.generate_a11y_summary(report),
         );
 
         Ok(html)
@@ -399,70 +469,225 @@ impl Reporter for HtmlReporter {
 impl HtmlReporter {
     fn generate_rows(&self, report: &TestReport) -> String {
         let mut rows = String::new();
+        let mut rendered_groups: Vec<&str> = Vec::new();
 
         for (idx, result) in report.results.iter().enumerate() {
-            let status_class = match result.status {
-                TestStatus::Passed => "passed",
-                TestStatus::Failed => "failed",
-                TestStatus::Skipped => "skipped",
+            let Some(group) = result.group.as_deref() else {
+                rows.push_str(&self.generate_row(idx, result, ""));
+                continue;
             };
+            if rendered_groups.contains(&group) {
+                continue;
+            }
+            rendered_groups.push(group);
 
-            let status_badge = match result.status {
-                TestStatus::Passed => r#"<span class="status-badge passed">✅ PASSED</span>"#,
-                TestStatus::Failed => r#"<span class="status-badge failed">❌ FAILED</span>"#,
-                TestStatus::Skipped => r#"<span class="status-badge">⏭️ SKIPPED</span>"#,
-            };
+            let group_id = format!("group-{}", rendered_groups.len());
+            let members: Vec<(usize, &TestResult)> = report
+                .results
+                .iter()
+                .enumerate()
+                .filter(|(_, r)| r.group.as_deref() == Some(group))
+                .collect();
+            let failed = members.iter().filter(|(_, r)| r.status.is_failed()).count();
+
+            rows.push_str(&format!(
+                r#"<tr class="group-header {group_class}" onclick="toggleGroup('{group_id}')">
+    <td colspan="6">▶ {group} — {total} cases • {passed} passed • {failed} failed</td>
+</tr>
+"#,
+                group_class = if failed > 0 { "failed" } else { "passed" },
+                group_id = group_id,
+                group = escape_html(group),
+                total = members.len(),
+                passed = members.len() - failed,
+                failed = failed,
+            ));
+            for (member_idx, member) in members {
+                rows.push_str(&self.generate_row(member_idx, member, &group_id));
+            }
+        }
+
+        rows
+    }
+
+    /// Accessibility violations grouped by the page they were found on.
+    fn generate_a11y_summary(&self, report: &TestReport) -> String {
+        let mut pages: BTreeMap<&str, Vec<&A11yViolation>> = BTreeMap::new();
+        for result in &report.results {
+            if !result.a11y.is_empty() {
+                pages
+                    .entry(result.endpoint.as_str())
+                    .or_default()
+                    .extend(&result.a11y);
+            }
+        }
+        if pages.is_empty() {
+            return String::new();
+        }
+
+        let mut html = String::from(
+            r#"<section class="a11y-summary">
+            <h2>♿ Accessibility</h2>
+"#,
+        );
+        for (page, violations) in pages {
+            let mut by_impact: BTreeMap<&str, usize> = BTreeMap::new();
+            for v in &violations {
+                *by_impact.entry(v.impact.as_str()).or_default() += 1;
+            }
+            let counts = by_impact
+                .iter()
+                .map(|(impact, count)| format!("{} {}", count, escape_html(impact)))
+                .collect::<Vec<_>>()
+                .join(" • ");
+
+            let rows = violations
+                .iter()
+                .map(|v| {
+                    format!(
+                        r#"<tr>
+    <td>{rule}</td>
+    <td class="impact-{impact}">{impact}</td>
+    <td class="endpoint">{selector}</td>
+    <td><a href="{help_url}" target="_blank">{help}</a></td>
+</tr>
+"#,
+                        rule = escape_html(&v.rule),
+                        impact = escape_html(&v.impact),
+                        selector = escape_html(&v.selector),
+                        help_url = escape_html(&v.help_url),
+                        help = escape_html(&v.help),
+                    )
+                })
+                .collect::<String>();
+
+            html.push_str(&format!(
+                r#"<div class="a11y-page">
+    <h3>{page} — {total} violations ({counts})</h3>
+    <div class="results-table"><table>
+        <thead><tr><th>Rule</th><th>Impact</th><th>Element</th><th>Help</th></tr></thead>
+        <tbody>{rows}</tbody>
+    </table></div>
+</div>
+"#,
+                page = escape_html(page),
+                total = violations.len(),
+                counts = counts,
+                rows = rows,
+            ));
+        }
+        html.push_str("</section>");
+        html
+    }
+
+    fn generate_row(&self, idx: usize, result: &TestResult, group_id: &str) -> String {
+        let status_class = match result.status {
+            TestStatus::Passed => "passed",
+            TestStatus::Failed => "failed",
+            TestStatus::Skipped => "skipped",
+        };
+
+        let status_badge = match result.status {
+            TestStatus::Passed => r#"<span class="status-badge passed">✅ PASSED</span>"#,
+            TestStatus::Failed => r#"<span class="status-badge failed">❌ FAILED</span>"#,
+            TestStatus::Skipped => r#"<span class="status-badge">⏭️ SKIPPED</span>"#,
+        };
 
-            let status_code_display = result
-                .status_code
-                .map(|c| c.to_string())
-                .unwrap_or_else(|| "—".to_string());
+        let status_code_display = result
+            .status_code
+            .map(|c| c.to_string())
+            .unwrap_or_else(|| "—".to_string());
 
-            let error_section = if result.status.is_failed() {
-                let error_msg = result.error_message.as_deref().unwrap_or("Unknown error");
+        let error_section = if result.status.is_failed() {
+            let error_msg = result.error_message.as_deref().unwrap_or("Unknown error");
 
-                let mut detail_html = format!(
-                    r#"<div class="error-details" id="error-{idx}">
+            let mut detail_html = format!(
+                r#"<div class="error-details" id="error-{idx}">
 <strong>Error:</strong> {error}
 "#,
-                    idx = idx,
-                    error = escape_html(error_msg)
-                );
+                idx = idx,
+                error = escape_html(error_msg)
+            );
 
-                if result.request_body.is_some() || result.response_body.is_some() {
-                    detail_html.push_str(r#"<div class="request-response">"#);
+            if result.request_body.is_some() || result.response_body.is_some() {
+                detail_html.push_str(r#"<div class="request-response">"#);
 
-                    if let Some(ref req) = result.request_body {
-                        detail_html.push_str(&format!(
-                            r#"<div><h4>Request Body</h4><pre>{}</pre></div>"#,
-                            escape_html(req)
-                        ));
-                    }
+                if let Some(ref req) = result.request_body {
+                    detail_html.push_str(&format!(
+                        r#"<div><h4>Request Body</h4><pre>{}</pre></div>"#,
+                        escape_html(req)
+                    ));
+                }
+
+                if let Some(ref resp) = result.response_body {
+                    detail_html.push_str(&format!(
+                        r#"<div><h4>Response Body</h4><pre>{}</pre></div>"#,
+                        escape_html(resp)
+                    ));
+                }
 
-                    if let Some(ref resp) = result.response_body {
+                detail_html.push_str("</div>");
+            }
+
+            if !result.attachments.is_empty() {
+                detail_html.push_str(r#"<div class="attachments">"#);
+                for attachment in &result.attachments {
+                    let path = escape_html(&attachment.path);
+                    let label = escape_html(&attachment.label);
+                    if attachment.is_image() {
                         detail_html.push_str(&format!(
-                            r#"<div><h4>Response Body</h4><pre>{}</pre></div>"#,
-                            escape_html(resp)
+                            r#"<a href="{path}" target="_blank"><img src="{path}" alt="{label}"></a>"#,
+                            path = path,
+                            label = label
                         ));
                     }
-
-                    detail_html.push_str("</div>");
+                    detail_html.push_str(&format!(
+                        r#"<a href="{}" target="_blank">📎 {}</a>"#,
+                        path, label
+                    ));
                 }
-
                 detail_html.push_str("</div>");
+            }
 
-                format!(
-                    r#"<br><span class="toggle-details" onclick="toggleDetails('error-{idx}')">▶ View Details</span>{detail_html}"#,
-                    idx = idx,
-                    detail_html = detail_html
-                )
-            } else {
-                String::new()
-            };
+            detail_html.push_str("</div>");
 
-            rows.push_str(&format!(
-                r#"<tr class="{status_class}">
-    <td>{step_name}{error_section}</td>
+            format!(
+                r#"<br><span class="toggle-details" onclick="toggleDetails('error-{idx}')">▶ View Details</span>{detail_html}"#,
+                idx = idx,
+                detail_html = detail_html
+            )
+        } else {
+            String::new()
+        };
+
+        let parameters_section = result
+            .parameters
+            .as_ref()
+            .map(|parameters| {
+                let pairs = parameters
+                    .iter()
+                    .map(|(key, value)| format!("{}={}", escape_html(key), escape_html(value)))
+                    .collect::<Vec<_>>()
+                    .join(" • ");
+                format!(r#"<br><span class="parameters">{}</span>"#, pairs)
+            })
+            .unwrap_or_default();
+
+        let metrics_section = if result.metrics.is_empty() {
+            String::new()
+        } else {
+            let pairs = result
+                .metrics
+                .iter()
+                .map(|(key, value)| format!("{}={:.2}", escape_html(key), value))
+                .collect::<Vec<_>>()
+                .join(" • ");
+            format!(r#"<br><span class="parameters">{}</span>"#, pairs)
+        };
+
+        format!(
+            r#"<tr class="{status_class} {group_id}">
+    <td>{step_name}{parameters_section}{metrics_section}{error_section}</td>
     <td><span class="method-badge method-{method}">{method}</span></td>
     <td class="endpoint">{endpoint}</td>
     <td>{status_code}</td>
@@ -470,18 +695,18 @@ impl HtmlReporter {
     <td>{status_badge}</td>
 </tr>
 "#,
-                status_class = status_class,
-                step_name = escape_html(&result.step_name),
-                error_section = error_section,
-                method = result.method,
-                endpoint = escape_html(&result.endpoint),
-                status_code = status_code_display,
-                duration = result.duration_ms,
-                status_badge = status_badge,
-            ));
-        }
-
-        rows
+            status_class = status_class,
+            step_name = escape_html(&result.step_name),
+            parameters_section = parameters_section,
+            metrics_section = metrics_section,
+            error_section = error_section,
+            method = result.method,
+            endpoint = escape_html(&result.endpoint),
+            status_code = status_code_display,
+            duration = result.duration_ms,
+            status_badge = status_badge,
+            group_id = group_id,
+        )
     }
 }
 
@@ -0,0 +1,31 @@
+pub mod html_reporter;
+pub mod reporter;
+pub mod test_collector;
+pub mod types;
+
+pub use html_reporter::HtmlReporter;
+pub use reporter::{ConsoleReporter, JUnitReporter, JsonReporter, Reporter};
+pub use test_collector::TestCollector;
+pub use types::{A11yViolation, Attachment, TestKind, TestReport, TestResult, TestStatus};
+
+use anyhow::Result;
+use std::path::Path;
+
+/// Writes `<name>.html`, `<name>.json` and `<name>.xml` into `dir`.
+pub fn save_all(report: &TestReport, dir: &Path, name: &str) -> Result<()> {
+    std::fs::create_dir_all(dir)?;
+
+    let html_path = dir.join(format!("{}.html", name));
+    HtmlReporter::new().save(report, &html_path)?;
+    println!("HTML Report: {}", html_path.display());
+
+    let json_path = dir.join(format!("{}.json", name));
+    JsonReporter::new().save(report, &json_path)?;
+    println!("JSON Report: {}", json_path.display());
+
+    let junit_path = dir.join(format!("{}.xml", name));
+    JUnitReporter::new().save(report, &junit_path)?;
+    println!("JUnit Report: {}", junit_path.display());
+
+    Ok(())
+}
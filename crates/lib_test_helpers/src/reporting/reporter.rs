@@ -1,4 +1,4 @@
-use super::types::{TestReport, TestStatus};
+use super::types::{TestKind, TestReport, TestStatus};
 use anyhow::Result;
 use std::fs;
 use std::path::Path;
@@ -26,7 +26,10 @@ impl ConsoleReporter {
     pub fn print(&self, report: &TestReport) {
         println!();
         println!("╔══════════════════════════════════════════════════════════════════════╗");
-        println!("║                         API TEST REPORT                              ║");
+        println!(
+            "║{:^70}║",
+            format!("{} REPORT", report.suite_name.to_uppercase())
+        );
         println!("╠══════════════════════════════════════════════════════════════════════╣");
         println!(
             "║  Total: {:<4} │  Passed: {:<4} │  Failed: {:<4} │  Pass Rate: {:>5.1}%  ║",
@@ -68,6 +71,9 @@ impl ConsoleReporter {
             report.format_duration(),
             report.start_time.format("%Y-%m-%d %H:%M:%S UTC")
         );
+        if let Some(seed) = report.seed {
+            println!("🎲 Seed: {} (re-run with --seed {})", seed, seed);
+        }
         println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
         println!();
     }
@@ -83,11 +89,14 @@ impl Reporter for ConsoleReporter {
     fn generate(&self, report: &TestReport) -> Result<String> {
         let mut output = String::new();
 
-        output.push_str("\nAPI TEST REPORT\n");
+        output.push_str(&format!("\n{} REPORT\n", report.suite_name.to_uppercase()));
         output.push_str(&format!(
             "Total: {} | Passed: {} | Failed: {} | Pass Rate: {:.1}%\n\n",
             report.total_tests, report.passed, report.failed, report.pass_rate
         ));
+        if let Some(seed) = report.seed {
+            output.push_str(&format!("Seed: {}\n\n", seed));
+        }
 
         for result in &report.results {
             let status = result.status.as_str();
@@ -156,7 +165,7 @@ impl Reporter for JUnitReporter {
         xml.push('\n');
         xml.push_str("<testsuites>\n");
         xml.push_str(&format!(
-            r#"  <testsuite id="0" name="{}" package="api_tests" tests="{}" errors="0" failures="{}" hostname="localhost" timestamp="{}" time="{:.3}">"#,
+            r#"  <testsuite id="0" name="{}" package="qa" tests="{}" errors="0" failures="{}" hostname="localhost" timestamp="{}" time="{:.3}">"#,
             escape_xml(&report.suite_name),
             report.total_tests,
             report.failed,
@@ -164,13 +173,21 @@ impl Reporter for JUnitReporter {
             report.total_duration_ms as f64 / 1000.0
         ));
         xml.push('\n');
+        if let Some(seed) = report.seed {
+            xml.push_str("    <properties>\n");
+            xml.push_str(&format!(
+                r#"      <property name="seed" value="{}"/>"#,
+                seed
+            ));
+            xml.push_str("\n    </properties>\n");
+        }
 
         for result in &report.results {
             let time_secs = result.duration_ms as f64 / 1000.0;
             xml.push_str(&format!(
                 r#"    <testcase name="{}" classname="{}" time="{:.3}">"#,
                 escape_xml(&result.step_name),
-                escape_xml(&result.api_name),
+                escape_xml(result.group.as_deref().unwrap_or(&result.api_name)),
                 time_secs
             ));
             xml.push('\n');
@@ -178,11 +195,15 @@ impl Reporter for JUnitReporter {
             if result.status == TestStatus::Failed {
                 let error_msg = result.error_message.as_deref().unwrap_or("Unknown error");
                 xml.push_str(&format!(
-                    r#"      <failure type="API Error" message="{}">"#,
+                    r#"      <failure type="{} Error" message="{}">"#,
+                    result.kind.as_str(),
                     escape_xml(error_msg)
                 ));
 
-                xml.push_str("\nEndpoint: ");
+                xml.push_str(match result.kind {
+                    TestKind::Ui => "\nURL: ",
+                    _ => "\nEndpoint: ",
+                });
                 xml.push_str(&escape_xml(&format!(
                     "{} {}",
                     result.method, result.endpoint
@@ -198,6 +219,14 @@ impl Reporter for JUnitReporter {
                 xml.push_str("</failure>\n");
             }
 
+            if !result.attachments.is_empty() {
+                xml.push_str("      <system-out>");
+                for attachment in &result.attachments {
+                    xml.push_str(&format!("[[ATTACHMENT|{}]]\n", escape_xml(&attachment.path)));
+                }
+                xml.push_str("</system-out>\n");
+            }
+
             xml.push_str("    </testcase>\n");
         }
 
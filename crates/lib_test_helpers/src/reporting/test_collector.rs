@@ -1,10 +1,10 @@
-use super::types::{ApiTestResult, TestReport};
+use super::types::{TestResult, TestReport};
 use chrono::Utc;
 use std::sync::{Arc, LazyLock, RwLock};
 use std::time::Instant;
 
 pub struct TestCollector {
-    results: Arc<RwLock<Vec<ApiTestResult>>>,
+    results: Arc<RwLock<Vec<TestResult>>>,
     suite_name: RwLock<String>,
     start_time: RwLock<Option<Instant>>,
 }
@@ -13,7 +13,7 @@ impl TestCollector {
     pub fn new() -> Self {
         Self {
             results: Arc::new(RwLock::new(Vec::new())),
-            suite_name: RwLock::new("API Test Suite".to_string()),
+            suite_name: RwLock::new("Test Suite".to_string()),
             start_time: RwLock::new(None),
         }
     }
@@ -35,7 +35,7 @@ impl TestCollector {
         }
     }
 
-    pub fn record(&self, result: ApiTestResult) {
+    pub fn record(&self, result: TestResult) {
         if let Ok(mut results) = self.results.write() {
             results.push(result);
         }
@@ -50,7 +50,7 @@ impl TestCollector {
         status_code: u16,
         duration_ms: u64,
     ) {
-        let result = ApiTestResult::passed(
+        let result = TestResult::passed(
             step_name,
             api_name,
             method,
@@ -61,6 +61,7 @@ impl TestCollector {
         self.record(result);
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn record_failed(
         &self,
         step_name: impl Into<String>,
@@ -71,7 +72,7 @@ impl TestCollector {
         duration_ms: u64,
         error: impl Into<String>,
     ) {
-        let result = ApiTestResult::failed(
+        let result = TestResult::failed(
             step_name,
             api_name,
             method,
@@ -83,6 +84,7 @@ impl TestCollector {
         self.record(result);
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn record_failed_with_details(
         &self,
         step_name: impl Into<String>,
@@ -95,7 +97,7 @@ impl TestCollector {
         request_body: Option<String>,
         response_body: Option<String>,
     ) {
-        let mut result = ApiTestResult::failed(
+        let mut result = TestResult::failed(
             step_name,
             api_name,
             method,
@@ -114,7 +116,7 @@ impl TestCollector {
             .suite_name
             .read()
             .map(|s| s.clone())
-            .unwrap_or_else(|_| "API Test Suite".to_string());
+            .unwrap_or_else(|_| "Test Suite".to_string());
 
         let results = self
             .results
@@ -131,6 +133,7 @@ impl TestCollector {
         }
 
         report.end_time = Utc::now();
+        report.seed = Some(crate::seed::current_seed());
         report
     }
 
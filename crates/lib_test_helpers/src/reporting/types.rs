@@ -0,0 +1,390 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TestStatus {
+    Passed,
+    Failed,
+    Skipped,
+}
+
+impl TestStatus {
+    pub fn is_passed(&self) -> bool {
+        matches!(self, TestStatus::Passed)
+    }
+
+    pub fn is_failed(&self) -> bool {
+        matches!(self, TestStatus::Failed)
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TestStatus::Passed => "PASSED",
+            TestStatus::Failed => "FAILED",
+            TestStatus::Skipped => "SKIPPED",
+        }
+    }
+
+    pub fn emoji(&self) -> &'static str {
+        match self {
+            TestStatus::Passed => "✅",
+            TestStatus::Failed => "❌",
+            TestStatus::Skipped => "⏭️",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TestKind {
+    #[default]
+    Api,
+    Ui,
+    Load,
+}
+
+impl TestKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TestKind::Api => "API",
+            TestKind::Ui => "UI",
+            TestKind::Load => "Load",
+        }
+    }
+}
+
+/// A file saved alongside the report; `path` is relative to the report directory.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Attachment {
+    pub label: String,
+    pub path: String,
+}
+
+impl Attachment {
+    pub fn new(label: impl Into<String>, path: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            path: path.into(),
+        }
+    }
+
+    pub fn is_image(&self) -> bool {
+        self.path.ends_with(".png")
+    }
+}
+
+/// One accessibility rule violation found on a page (one entry per offending element).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct A11yViolation {
+    pub rule: String,
+    pub impact: String,
+    pub selector: String,
+    pub help: String,
+    pub help_url: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TestResult {
+    #[serde(default)]
+    pub kind: TestKind,
+
+    pub step_name: String,
+
+    pub api_name: String,
+
+    pub method: String,
+
+    pub endpoint: String,
+
+    pub status_code: Option<u16>,
+
+    pub duration_ms: u64,
+
+    pub status: TestStatus,
+
+    pub error_message: Option<String>,
+
+    pub request_body: Option<String>,
+
+    pub response_body: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<BTreeMap<String, String>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<Attachment>,
+
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub metrics: BTreeMap<String, f64>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub a11y: Vec<A11yViolation>,
+
+    pub timestamp: DateTime<Utc>,
+}
+
+impl TestResult {
+    pub fn passed(
+        step_name: impl Into<String>,
+        api_name: impl Into<String>,
+        method: impl Into<String>,
+        endpoint: impl Into<String>,
+        status_code: u16,
+        duration_ms: u64,
+    ) -> Self {
+        Self {
+            kind: TestKind::Api,
+            step_name: step_name.into(),
+            api_name: api_name.into(),
+            method: method.into(),
+            endpoint: endpoint.into(),
+            status_code: Some(status_code),
+            duration_ms,
+            status: TestStatus::Passed,
+            error_message: None,
+            request_body: None,
+            response_body: None,
+            parameters: None,
+            group: None,
+            attachments: Vec::new(),
+            metrics: BTreeMap::new(),
+            a11y: Vec::new(),
+            timestamp: Utc::now(),
+        }
+    }
+
+    pub fn failed(
+        step_name: impl Into<String>,
+        api_name: impl Into<String>,
+        method: impl Into<String>,
+        endpoint: impl Into<String>,
+        status_code: Option<u16>,
+        duration_ms: u64,
+        error: impl Into<String>,
+    ) -> Self {
+        Self {
+            kind: TestKind::Api,
+            step_name: step_name.into(),
+            api_name: api_name.into(),
+            method: method.into(),
+            endpoint: endpoint.into(),
+            status_code,
+            duration_ms,
+            status: TestStatus::Failed,
+            error_message: Some(error.into()),
+            request_body: None,
+            response_body: None,
+            parameters: None,
+            group: None,
+            attachments: Vec::new(),
+            metrics: BTreeMap::new(),
+            a11y: Vec::new(),
+            timestamp: Utc::now(),
+        }
+    }
+
+    /// A browser step: no HTTP status, the page URL in place of an endpoint.
+    pub fn ui_passed(
+        step_name: impl Into<String>,
+        url: impl Into<String>,
+        duration_ms: u64,
+    ) -> Self {
+        Self {
+            status_code: None,
+            ..Self::passed(step_name, "E2E", "UI", url, 0, duration_ms)
+        }
+        .with_kind(TestKind::Ui)
+    }
+
+    pub fn ui_failed(
+        step_name: impl Into<String>,
+        url: impl Into<String>,
+        duration_ms: u64,
+        error: impl Into<String>,
+    ) -> Self {
+        Self::failed(step_name, "E2E", "UI", url, None, duration_ms, error).with_kind(TestKind::Ui)
+    }
+
+    pub fn skipped(
+        step_name: impl Into<String>,
+        api_name: impl Into<String>,
+        reason: impl Into<String>,
+    ) -> Self {
+        let mut result = Self::failed(step_name, api_name, "", "", None, 0, reason);
+        result.status = TestStatus::Skipped;
+        result
+    }
+
+    pub fn with_kind(mut self, kind: TestKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    pub fn with_metric(mut self, name: impl Into<String>, value: f64) -> Self {
+        self.metrics.insert(name.into(), value);
+        self
+    }
+
+    pub fn with_request_body(mut self, body: impl Into<String>) -> Self {
+        self.request_body = Some(body.into());
+        self
+    }
+
+    pub fn with_response_body(mut self, body: impl Into<String>) -> Self {
+        self.response_body = Some(body.into());
+        self
+    }
+
+    pub fn with_parameters(mut self, parameters: BTreeMap<String, String>) -> Self {
+        self.parameters = Some(parameters);
+        self
+    }
+
+    pub fn with_group(mut self, group: impl Into<String>) -> Self {
+        self.group = Some(group.into());
+        self
+    }
+
+    pub fn with_attachments(mut self, attachments: Vec<Attachment>) -> Self {
+        self.attachments.extend(attachments);
+        self
+    }
+
+    pub fn with_a11y(mut self, violations: Vec<A11yViolation>) -> Self {
+        self.a11y.extend(violations);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TestReport {
+    pub suite_name: String,
+
+    pub start_time: DateTime<Utc>,
+    pub end_time: DateTime<Utc>,
+
+    pub total_duration_ms: u64,
+
+    pub results: Vec<TestResult>,
+
+    pub total_tests: usize,
+
+    pub passed: usize,
+
+    pub failed: usize,
+    pub skipped: usize,
+    pub pass_rate: f64,
+
+    #[serde(default)]
+    pub seed: Option<u64>,
+}
+
+impl TestReport {
+    pub fn new(suite_name: impl Into<String>, results: Vec<TestResult>) -> Self {
+        let total_tests = results.len();
+        let passed = results.iter().filter(|r| r.status.is_passed()).count();
+        let failed = results.iter().filter(|r| r.status.is_failed()).count();
+        let skipped = total_tests - passed - failed;
+        let pass_rate = if total_tests > 0 {
+            (passed as f64 / total_tests as f64) * 100.0
+        } else {
+            0.0
+        };
+
+        let total_duration_ms: u64 = results.iter().map(|r| r.duration_ms).sum();
+
+        let start_time = results
+            .first()
+            .map(|r| r.timestamp)
+            .unwrap_or_else(Utc::now);
+        let end_time = results.last().map(|r| r.timestamp).unwrap_or_else(Utc::now);
+
+        Self {
+            suite_name: suite_name.into(),
+            start_time,
+            end_time,
+            total_duration_ms,
+            results,
+            total_tests,
+            passed,
+            failed,
+            skipped,
+            pass_rate,
+            seed: None,
+        }
+    }
+
+    /// Combines suite reports into one, grouping each suite's ungrouped results under
+    /// the suite name so they stay distinguishable.
+    pub fn merge(suite_name: impl Into<String>, reports: Vec<TestReport>) -> Self {
+        let start_time = reports.iter().map(|r| r.start_time).min();
+        let end_time = reports.iter().map(|r| r.end_time).max();
+        let total_duration_ms = reports.iter().map(|r| r.total_duration_ms).sum();
+        let seed = reports.iter().find_map(|r| r.seed);
+
+        let results = reports
+            .into_iter()
+            .flat_map(|report| {
+                let suite = report.suite_name;
+                report.results.into_iter().map(move |mut result| {
+                    if result.group.is_none() {
+                        result.group = Some(suite.clone());
+                    }
+                    result
+                })
+            })
+            .collect();
+
+        let mut merged = Self::new(suite_name, results);
+        merged.start_time = start_time.unwrap_or(merged.start_time);
+        merged.end_time = end_time.unwrap_or(merged.end_time);
+        merged.total_duration_ms = total_duration_ms;
+        merged.seed = seed;
+        merged
+    }
+
+    /// Re-roots attachment links when the report is written somewhere other than the
+    /// directory its artifacts were saved relative to.
+    pub fn with_attachment_prefix(mut self, prefix: &str) -> Self {
+        let prefix = prefix.trim_end_matches('/');
+        for attachment in self
+            .results
+            .iter_mut()
+            .flat_map(|r| r.attachments.iter_mut())
+        {
+            attachment.path = format!("{}/{}", prefix, attachment.path);
+        }
+        self
+    }
+
+    pub fn failed_tests(&self) -> Vec<&TestResult> {
+        self.results
+            .iter()
+            .filter(|r| r.status.is_failed())
+            .collect()
+    }
+
+    pub fn passed_tests(&self) -> Vec<&TestResult> {
+        self.results
+            .iter()
+            .filter(|r| r.status.is_passed())
+            .collect()
+    }
+
+    pub fn has_failures(&self) -> bool {
+        self.failed > 0
+    }
+
+    pub fn format_duration(&self) -> String {
+        let secs = self.total_duration_ms / 1000;
+        let ms = self.total_duration_ms % 1000;
+        if secs > 0 {
+            format!("{}.{:03}s", secs, ms)
+        } else {
+            format!("{}ms", ms)
+        }
+    }
+}
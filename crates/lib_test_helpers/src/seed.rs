@@ -0,0 +1,78 @@
+use once_cell::sync::OnceCell;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::cell::RefCell;
+use std::env;
+use std::future::Future;
+use std::sync::Mutex;
+
+static SEED: OnceCell<u64> = OnceCell::new();
+static RNG: OnceCell<Mutex<StdRng>> = OnceCell::new();
+
+tokio::task_local! {
+    static SCOPED_RNG: RefCell<StdRng>;
+}
+
+fn resolve_seed(seed: Option<u64>) -> u64 {
+    seed.or_else(|| env::var("QA_SEED").ok().and_then(|s| s.parse().ok()))
+        .unwrap_or_else(|| rand::thread_rng().gen())
+}
+
+pub fn init_seed(seed: Option<u64>) -> u64 {
+    let seed = *SEED.get_or_init(|| resolve_seed(seed));
+    println!("🎲 Test data seed: {} (re-run with --seed {})", seed, seed);
+    seed
+}
+
+pub fn current_seed() -> u64 {
+    *SEED.get_or_init(|| resolve_seed(None))
+}
+
+/// Seed for the generator `scoped(key, ..)` installs: the run seed mixed with an FNV-1a hash
+/// of `key`, so it is the same on every platform and toolchain.
+pub fn child_seed(key: &str) -> u64 {
+    let hash = key.bytes().fold(0xcbf2_9ce4_8422_2325u64, |hash, b| {
+        (hash ^ b as u64).wrapping_mul(0x0000_0100_0000_01b3)
+    });
+    current_seed() ^ hash
+}
+
+/// Runs `future` with its own generator seeded from `child_seed(key)`. Concurrent scenarios
+/// each get one, so what they draw does not depend on how they interleave and `--seed`
+/// reproduces their data.
+pub async fn scoped<F: Future>(key: &str, future: F) -> F::Output {
+    let rng = RefCell::new(StdRng::seed_from_u64(child_seed(key)));
+    SCOPED_RNG.scope(rng, future).await
+}
+
+/// Draws from the generator of the enclosing `scoped` call, or the run-wide one outside it.
+pub fn with_rng<T>(f: impl FnOnce(&mut StdRng) -> T) -> T {
+    if SCOPED_RNG.try_with(|_| ()).is_ok() {
+        return SCOPED_RNG.with(|rng| f(&mut rng.borrow_mut()));
+    }
+    let rng = RNG.get_or_init(|| Mutex::new(StdRng::seed_from_u64(current_seed())));
+    let mut guard = rng.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    f(&mut guard)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn draws(n: usize) -> Vec<u32> {
+        let mut out = Vec::new();
+        for _ in 0..n {
+            out.push(with_rng(|rng| rng.gen()));
+            tokio::task::yield_now().await;
+        }
+        out
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn scoped_draws_do_not_depend_on_interleaving() {
+        let alone = scoped("a", draws(5)).await;
+        let (a, _) = tokio::join!(scoped("a", draws(5)), scoped("b", draws(5)));
+        assert_eq!(alone, a);
+        assert_ne!(child_seed("a"), child_seed("b"));
+    }
+}
@@ -1,85 +1,214 @@
+use crate::config::{get_config, Persona};
 use anyhow::{Context, Result};
-use serde_json::Value;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 use std::fs;
+use std::path::PathBuf;
 use thirtyfour::prelude::*;
 
-pub async fn save_session(driver: &WebDriver, prefix: &str) -> Result<()> {
-    let local_storage: Value = driver
-        .execute(
-            r#"
+const READ_STORAGE: &str = r#"
+    const read = (storage) => {
         const out = {};
-        for (let i = 0; i < localStorage.length; i++) {
-            const k = localStorage.key(i);
-            out[k] = localStorage.getItem(k);
+        for (let i = 0; i < storage.length; i++) {
+            const k = storage.key(i);
+            out[k] = storage.getItem(k);
         }
         return out;
-        "#,
-            Vec::<serde_json::Value>::new(),
-        )
-        .await?
-        .convert()?;
+    };
+    return { local: read(window.localStorage), session: read(window.sessionStorage) };
+"#;
+
+// Sends the stored bearer token (if any) along with cookies, like the app's own API calls.
+const PROBE: &str = r#"
+    const [url, tokenKey] = arguments;
+    const storages = [window.localStorage, window.sessionStorage];
+    const isJwt = (v) => typeof v === 'string' && /^[\w-]+\.[\w-]+\.[\w-]+$/.test(v.replace(/^"|"$/g, ''));
+    let token = null;
+    for (const storage of storages) {
+        if (tokenKey && storage.getItem(tokenKey)) { token = storage.getItem(tokenKey); break; }
+        if (!tokenKey) {
+            for (let i = 0; i < storage.length && !token; i++) {
+                const v = storage.getItem(storage.key(i));
+                if (isJwt(v)) token = v;
+            }
+        }
+    }
+    const headers = token ? { Authorization: 'Bearer ' + token.replace(/^"|"$/g, '') } : {};
+    return fetch(url, { credentials: 'include', headers })
+        .then(r => ({ ok: r.ok, status: r.status }))
+        .catch(e => ({ ok: false, status: 0 }));
+"#;
 
-    let ls_file = format!("{}_local_storage.json", prefix);
-    fs::write(&ls_file, serde_json::to_string_pretty(&local_storage)?)?;
+/// Identifies a cached login: one file per environment and persona under `session.cache_dir`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionKey {
+    pub env: String,
+    pub persona: String,
+}
 
-    let cookies = driver.get_all_cookies().await?;
-    let ck_file = format!("{}_cookies.json", prefix);
-    let ck_json = serde_json::to_string_pretty(&cookies)?;
-    fs::write(&ck_file, ck_json)?;
+impl SessionKey {
+    pub fn new(env: impl Into<String>, persona: impl Into<String>) -> Self {
+        Self {
+            env: env.into(),
+            persona: persona.into(),
+        }
+    }
+
+    pub fn for_persona(persona: impl Into<String>) -> Self {
+        Self::new(get_config().env.clone(), persona)
+    }
+
+    /// Keyed by email rather than persona name, so switching accounts never reuses another
+    /// user's session.
+    pub fn of(persona: &Persona) -> Self {
+        Self::for_persona(persona.email.clone())
+    }
+
+    pub fn default_user() -> Self {
+        Self::of(&Persona::default_user())
+    }
+
+    pub fn path(&self) -> PathBuf {
+        let safe = |s: &str| -> String {
+            s.chars()
+                .map(|c| {
+                    if c.is_ascii_alphanumeric() || c == '-' {
+                        c
+                    } else {
+                        '_'
+                    }
+                })
+                .collect()
+        };
+        PathBuf::from(&get_config().session.cache_dir)
+            .join(safe(&self.env))
+            .join(format!("{}.json", safe(&self.persona)))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedSession {
+    pub saved_at: DateTime<Utc>,
+    pub cookies: Vec<Cookie>,
+    pub local_storage: Map<String, Value>,
+    pub session_storage: Map<String, Value>,
+}
 
+impl SavedSession {
+    /// Why the session can't be reused, or `None` if it still looks valid.
+    pub fn stale_reason(&self) -> Option<String> {
+        let now = Utc::now();
+        let max_age = Duration::hours(get_config().session.max_age_hours as i64);
+        if now - self.saved_at > max_age {
+            return Some(format!(
+                "saved at {}, older than {:?}",
+                self.saved_at, max_age
+            ));
+        }
+        self.cookies
+            .iter()
+            .find(|c| c.expiry.is_some_and(|expiry| expiry <= now.timestamp()))
+            .map(|c| format!("cookie '{}' has expired", c.name))
+    }
+}
+
+pub async fn save_session(driver: &WebDriver, key: &SessionKey) -> Result<()> {
+    let storage: Value = driver.execute(READ_STORAGE, Vec::new()).await?.convert()?;
+    let as_map = |v: Option<&Value>| v.and_then(Value::as_object).cloned().unwrap_or_default();
+
+    let session = SavedSession {
+        saved_at: Utc::now(),
+        cookies: driver.get_all_cookies().await?,
+        local_storage: as_map(storage.get("local")),
+        session_storage: as_map(storage.get("session")),
+    };
+
+    let path = key.path();
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(&path, serde_json::to_string_pretty(&session)?)
+        .with_context(|| format!("Failed to write {}", path.display()))?;
     Ok(())
 }
 
-pub async fn restore_session(driver: &WebDriver, prefix: &str, base_url: &str) -> Result<()> {
+pub fn load_session(key: &SessionKey) -> Result<Option<SavedSession>> {
+    let path = key.path();
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = fs::read_to_string(&path)?;
+    let session = serde_json::from_str(&content)
+        .with_context(|| format!("Invalid session file {}", path.display()))?;
+    Ok(Some(session))
+}
+
+pub fn clear_session(key: &SessionKey) {
+    let _ = fs::remove_file(key.path());
+}
+
+/// Restores cookies, localStorage and sessionStorage from the cache. Returns `false` when
+/// there is nothing usable to restore; stale entries are deleted.
+pub async fn restore_session(driver: &WebDriver, key: &SessionKey, base_url: &str) -> Result<bool> {
+    let session = match load_session(key)? {
+        Some(session) => session,
+        None => return Ok(false),
+    };
+    if let Some(reason) = session.stale_reason() {
+        println!(
+            "Cached session for {}/{} is stale: {}",
+            key.env, key.persona, reason
+        );
+        clear_session(key);
+        return Ok(false);
+    }
+
     driver
         .goto(base_url)
         .await
         .context("goto base_url failed")?;
-    let ck_file = format!("{}_cookies.json", prefix);
-    if let Ok(ck_contents) = fs::read_to_string(&ck_file) {
-        let cookies: Vec<thirtyfour::Cookie> =
-            serde_json::from_str(&ck_contents).context("failed to parse cookies file")?;
-        for cookie in cookies {
-            driver.add_cookie(cookie).await?;
-        }
+    for cookie in session.cookies {
+        driver.add_cookie(cookie).await?;
     }
-
-    let ls_file = format!("{}_local_storage.json", prefix);
-    if let Ok(ls_contents) = fs::read_to_string(&ls_file) {
-        let map: serde_json::Map<String, Value> =
-            serde_json::from_str(&ls_contents).context("failed to parse local storage file")?;
-        for (k, v) in map {
+    for (storage, entries) in [
+        ("localStorage", session.local_storage),
+        ("sessionStorage", session.session_storage),
+    ] {
+        for (k, v) in entries {
             let value_str = v
                 .as_str()
                 .map(|s| s.to_string())
                 .unwrap_or_else(|| v.to_string());
             driver
                 .execute(
-                    "window.localStorage.setItem(arguments[0], arguments[1]);",
-                    vec![
-                        serde_json::Value::String(k),
-                        serde_json::Value::String(value_str),
-                    ],
+                    &format!("window.{}.setItem(arguments[0], arguments[1]);", storage),
+                    vec![Value::String(k), Value::String(value_str)],
                 )
                 .await?;
         }
     }
     driver.refresh().await?;
-    Ok(())
+    Ok(true)
 }
 
-pub async fn check_logged_in(driver: &WebDriver, check_url: &str) -> Result<bool> {
-    let script = r#"
-        return fetch(arguments[0], { credentials: 'include' })
-            .then(r => ({ ok: r.ok, status: r.status }))
-            .catch(e => ({ ok: false, status: 0 }));
-    "#;
-
-    let res: serde_json::Value = driver
-        .execute(
-            script,
-            vec![serde_json::Value::String(check_url.to_string())],
-        )
+/// Calls `session.probe_path` from inside the page; only a 2xx counts as logged in.
+pub async fn check_logged_in(driver: &WebDriver) -> Result<bool> {
+    let cfg = get_config();
+    let url = format!(
+        "{}/{}",
+        cfg.api_base_url.trim_end_matches('/'),
+        cfg.session.probe_path.trim_start_matches('/')
+    );
+    let token_key = cfg
+        .session
+        .token_key
+        .clone()
+        .map(Value::String)
+        .unwrap_or(Value::Null);
+
+    let res: Value = driver
+        .execute(PROBE, vec![Value::String(url), token_key])
         .await?
         .convert()?;
     let ok = res.get("ok").and_then(|v| v.as_bool()).unwrap_or(false);
@@ -0,0 +1,223 @@
+use crate::config::get_config;
+use serde_json::{json, Value};
+use std::cell::Cell;
+use std::future::Future;
+use std::time::{Duration, Instant};
+use thirtyfour::extensions::cdp::ChromeDevTools;
+use thirtyfour::prelude::*;
+
+#[derive(Debug, Clone, Copy)]
+pub struct WaitPolicy {
+    pub timeout: Duration,
+    pub page_load_timeout: Duration,
+    pub poll_interval: Duration,
+    pub network_quiet: Duration,
+}
+
+impl WaitPolicy {
+    pub fn global() -> Self {
+        let wait = &get_config().wait;
+        Self {
+            timeout: Duration::from_millis(wait.timeout_ms),
+            page_load_timeout: Duration::from_millis(wait.page_load_timeout_ms),
+            poll_interval: Duration::from_millis(wait.poll_interval_ms),
+            network_quiet: Duration::from_millis(wait.network_quiet_ms),
+        }
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn for_page_load(self) -> Self {
+        self.with_timeout(self.page_load_timeout)
+    }
+
+    /// Polls `check` until it yields a value. Stale, missing or covered elements are
+    /// treated as "not yet" rather than failures, since re-renders produce them constantly.
+    pub async fn until<T, F, Fut>(&self, what: &str, mut check: F) -> WebDriverResult<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = WebDriverResult<Option<T>>>,
+    {
+        let deadline = Instant::now() + self.timeout;
+        let mut last_err = None;
+        loop {
+            match check().await {
+                Ok(Some(value)) => return Ok(value),
+                Ok(None) => {}
+                Err(e) if is_transient(&e) => last_err = Some(e),
+                Err(e) => return Err(e),
+            }
+            if Instant::now() >= deadline {
+                let detail = last_err
+                    .map(|e| format!(" (last error: {})", e))
+                    .unwrap_or_default();
+                return Err(WebDriverError::Timeout(format!(
+                    "Timed out after {:?} waiting for {}{}",
+                    self.timeout, what, detail
+                )));
+            }
+            tokio::time::sleep(self.poll_interval).await;
+        }
+    }
+}
+
+fn is_transient(err: &WebDriverError) -> bool {
+    matches!(
+        err,
+        WebDriverError::StaleElementReference(_)
+            | WebDriverError::NoSuchElement(_)
+            | WebDriverError::ElementClickIntercepted(_)
+            | WebDriverError::ElementNotInteractable(_)
+    )
+}
+
+// Wraps fetch and XMLHttpRequest once per document to count the requests in flight.
+const NETWORK_TRACKER: &str = r#"
+if (!window.__qaNetwork) {
+    const state = { pending: 0, lastChange: Date.now() };
+    window.__qaNetwork = state;
+    const begin = () => { state.pending++; state.lastChange = Date.now(); };
+    const end = () => { state.pending = Math.max(0, state.pending - 1); state.lastChange = Date.now(); };
+    if (window.fetch) {
+        const originalFetch = window.fetch;
+        window.fetch = function (...args) {
+            begin();
+            return originalFetch.apply(this, args).finally(end);
+        };
+    }
+    const originalSend = XMLHttpRequest.prototype.send;
+    XMLHttpRequest.prototype.send = function (...args) {
+        begin();
+        this.addEventListener('loadend', end, { once: true });
+        return originalSend.apply(this, args);
+    };
+}
+"#;
+
+// [pending requests, ms since the count last changed]
+const NETWORK_STATUS: &str =
+    "return [window.__qaNetwork.pending, Date.now() - window.__qaNetwork.lastChange];";
+
+const BUSY_INDICATORS: &str = r#"
+const selectors = '.ant-spin-spinning, .ant-skeleton-active, .ant-btn-loading, .ant-table-loading';
+return Array.from(document.querySelectorAll(selectors))
+    .filter(el => el.offsetParent !== null || getComputedStyle(el).position === 'fixed')
+    .length;
+"#;
+
+/// Registers the request tracker for every document the session loads from now on, so
+/// requests a page fires while loading are counted. Without CDP (Firefox) it is injected
+/// into the current document only, and `wait_for_network_idle` re-injects it after navigation.
+pub async fn install_network_tracker(driver: &WebDriver) -> WebDriverResult<()> {
+    let dev_tools = ChromeDevTools::new(driver.handle.clone());
+    let _ = dev_tools
+        .execute_cdp_with_params(
+            "Page.addScriptToEvaluateOnNewDocument",
+            json!({ "source": NETWORK_TRACKER }),
+        )
+        .await;
+    driver.execute(NETWORK_TRACKER, Vec::new()).await?;
+    Ok(())
+}
+
+pub async fn wait_for_document_ready(driver: &WebDriver) -> WebDriverResult<()> {
+    WaitPolicy::global()
+        .for_page_load()
+        .until("document to finish loading", || async {
+            let state: String = driver
+                .execute("return document.readyState;", Vec::new())
+                .await?
+                .convert()?;
+            Ok((state == "complete").then_some(()))
+        })
+        .await
+}
+
+pub async fn wait_for_network_idle(driver: &WebDriver) -> WebDriverResult<()> {
+    let policy = WaitPolicy::global().for_page_load();
+    let quiet_ms = policy.network_quiet.as_millis() as u64;
+    let script = format!("{}{}", NETWORK_TRACKER, NETWORK_STATUS);
+    policy
+        .until("network to go idle", || async {
+            let status: Vec<Value> = driver.execute(&script, Vec::new()).await?.convert()?;
+            let pending = status.first().and_then(Value::as_u64).unwrap_or(0);
+            let quiet_for = status.get(1).and_then(Value::as_u64).unwrap_or(0);
+            Ok((pending == 0 && quiet_for >= quiet_ms).then_some(()))
+        })
+        .await
+}
+
+pub async fn wait_for_spinners(driver: &WebDriver) -> WebDriverResult<()> {
+    WaitPolicy::global()
+        .for_page_load()
+        .until("loading indicators to disappear", || async {
+            let busy: u64 = driver
+                .execute(BUSY_INDICATORS, Vec::new())
+                .await?
+                .convert()?;
+            Ok((busy == 0).then_some(()))
+        })
+        .await
+}
+
+pub async fn wait_for_page_ready(driver: &WebDriver) -> WebDriverResult<()> {
+    wait_for_document_ready(driver).await?;
+    wait_for_network_idle(driver).await?;
+    wait_for_spinners(driver).await
+}
+
+/// Waits until the element's position and size stop changing between polls, so clicks
+/// don't land mid-animation (drawers sliding in, modals zooming).
+pub async fn wait_for_stable(element: &WebElement) -> WebDriverResult<()> {
+    let last = Cell::new(None);
+    WaitPolicy::global()
+        .until("element to stop moving", || async {
+            let rect = element.rect().await?;
+            let current = (rect.x, rect.y, rect.width, rect.height);
+            let stable = last.get() == Some(current);
+            last.set(Some(current));
+            Ok(stable.then_some(()))
+        })
+        .await
+}
+
+pub async fn wait_for_route_change(
+    driver: &WebDriver,
+    previous_url: &str,
+) -> WebDriverResult<String> {
+    WaitPolicy::global()
+        .for_page_load()
+        .until(
+            &format!("route to change from {}", previous_url),
+            || async {
+                let url = driver.current_url().await?.to_string();
+                Ok((url != previous_url).then_some(url))
+            },
+        )
+        .await
+}
+
+pub async fn wait_for_route(driver: &WebDriver, fragment: &str) -> WebDriverResult<String> {
+    WaitPolicy::global()
+        .for_page_load()
+        .until(&format!("route containing '{}'", fragment), || async {
+            let url = driver.current_url().await?.to_string();
+            Ok(url.contains(fragment).then_some(url))
+        })
+        .await
+}
+
+pub async fn wait_for_clickable(driver: &WebDriver, locator: By) -> WebDriverResult<WebElement> {
+    let policy = WaitPolicy::global();
+    let element = driver
+        .query(locator)
+        .wait(policy.timeout, policy.poll_interval)
+        .and_clickable()
+        .first()
+        .await?;
+    wait_for_stable(&element).await?;
+    Ok(element)
+}
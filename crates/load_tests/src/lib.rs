@@ -1 +1,12 @@
+use lib_test_helpers::reporting::{TestKind, TestReport, TestResult};
+
 pub fn run_load() { println!("Running load tests"); }
+
+pub fn run_suite() -> TestReport {
+    run_load();
+    TestReport::new(
+        "Load Test Suite",
+        vec![TestResult::skipped("Load scenarios", "Load", "No load scenarios configured")
+            .with_kind(TestKind::Load)],
+    )
+}
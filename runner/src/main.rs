@@ -1,42 +1,172 @@
-use anyhow::Result;
-use clap::{Parser, Subcommand};
-use lib_test_helpers::chrome_driver::chrome;
-use lib_test_helpers::config::get_config;
+use anyhow::{bail, Result};
+use clap::{Args, Parser, Subcommand};
+use lib_test_helpers::config::{get_config, init_config, ConfigOverrides, WindowSize};
+use lib_test_helpers::driver_service::{DriverKind, DriverService};
+use lib_test_helpers::reporting::{self, ConsoleReporter, TestReport, TestResult};
+use std::path::Path;
+use std::time::Duration;
 
 #[derive(Parser)]
 #[command(name = "qa-runner", version, about = "QA Automation Orchestrator")]
 struct Cli {
     #[command(subcommand)]
     command: Commands,
+
+    #[arg(long, global = true)]
+    seed: Option<u64>,
+
+    #[command(flatten)]
+    browser: BrowserArgs,
+}
+
+// Handed to the config loader, so each flag overrides both the YAML and .env.
+#[derive(Args)]
+struct BrowserArgs {
+    #[arg(long, global = true)]
+    browser: Option<String>,
+    /// `--headless` or `--headless=false`; unset keeps the YAML/HEADLESS setting
+    #[arg(
+        long,
+        global = true,
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true"
+    )]
+    headless: Option<bool>,
+    /// e.g. 1280x800
+    #[arg(long, global = true)]
+    window_size: Option<String>,
+    /// e.g. pixel-7, iphone-14, ipad-air
+    #[arg(long, global = true)]
+    device: Option<String>,
+    #[arg(long, global = true)]
+    locale: Option<String>,
+    #[arg(long, global = true)]
+    timezone: Option<String>,
+    #[arg(long, global = true)]
+    download_dir: Option<String>,
+    /// Overwrite visual baselines with the current screenshots instead of comparing. None are
+    /// committed, so run with this once on a fresh checkout (see crates/e2e_tests/README.md)
+    #[arg(long, global = true)]
+    update_baselines: bool,
+}
+
+impl BrowserArgs {
+    fn overrides(self) -> Result<ConfigOverrides> {
+        let window_size = match self.window_size {
+            Some(size) => match WindowSize::parse(&size) {
+                Some(size) => Some(size),
+                None => bail!("Invalid --window-size '{}', expected e.g. 1280x800", size),
+            },
+            None => None,
+        };
+        Ok(ConfigOverrides {
+            browser: self.browser,
+            headless: self.headless,
+            window_size,
+            device: self.device,
+            locale: self.locale,
+            timezone: self.timezone,
+            download_dir: self.download_dir,
+            update_baselines: self.update_baselines,
+        })
+    }
 }
 
 #[derive(Subcommand)]
 enum Commands {
     E2E,
-    Api,
+    Api {
+        #[arg(long)]
+        data_driven: bool,
+        #[arg(long)]
+        row_pacing_ms: Option<u64>,
+        #[arg(long)]
+        negative: bool,
+        #[arg(long)]
+        fuzz: bool,
+        #[arg(long)]
+        fuzz_cases: Option<u32>,
+        #[arg(long)]
+        lead_stages: bool,
+        #[arg(long)]
+        lead_stage_walks: Option<u32>,
+        #[arg(long)]
+        cart_model: bool,
+        #[arg(long)]
+        cart_sequences: Option<u32>,
+        #[arg(long)]
+        concurrency: bool,
+        #[arg(long)]
+        concurrent_requests: Option<usize>,
+    },
     Load,
     All,
     Config,
 }
 
-#[tokio::main]
-async fn main() -> Result<()> {
+// A remote or already-running driver is used as-is when WEBDRIVER_URL is set.
+fn start_driver() -> Result<Option<DriverService>> {
+    if std::env::var("WEBDRIVER_URL").is_ok() {
+        return Ok(None);
+    }
+    let browser = &get_config().browser;
+    let mut builder = DriverService::builder(DriverKind::from_name(&browser.name));
+    if let Some(timezone) = &browser.timezone {
+        builder = builder.timezone(timezone);
+    }
+    builder.start().map(Some)
+}
+
+// .env is loaded before the runtime starts: it sets environment variables, which is only
+// sound while the process is single-threaded.
+fn main() -> Result<()> {
+    dotenvy::dotenv().ok();
     let cli = Cli::parse();
+    init_config(cli.browser.overrides()?);
+    lib_test_helpers::seed::init_seed(cli.seed);
+
+    tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()?
+        .block_on(run(cli.command))
+}
 
-    match cli.command {
+async fn run(command: Commands) -> Result<()> {
+    match command {
         Commands::E2E => {
             println!("Running E2E tests...");
-            let mut cd = chrome();
+            let _driver = start_driver()?;
             e2e_tests::run().await?;
-            // if let Err(e) = e2e_tests::run().await {
-            //     let _ = cd.kill();
-            //     return Err(e);
-            // }
-            cd.kill().expect("kill process");
         }
-        Commands::Api => {
+        Commands::Api {
+            data_driven,
+            row_pacing_ms,
+            negative,
+            fuzz,
+            fuzz_cases,
+            lead_stages,
+            lead_stage_walks,
+            cart_model,
+            cart_sequences,
+            concurrency,
+            concurrent_requests,
+        } => {
             println!("Running API tests...");
-            api_tests::run_all_tests().await?;
+            api_tests::run_tests(api_tests::ApiTestOptions {
+                data_driven,
+                row_pacing: row_pacing_ms.map(Duration::from_millis),
+                negative,
+                fuzz,
+                fuzz_cases,
+                lead_stages,
+                lead_stage_walks,
+                cart_model,
+                cart_sequences,
+                concurrency,
+                concurrent_requests,
+            })
+            .await?;
         }
         Commands::Load => {
             println!("Running load tests...");
@@ -44,9 +174,29 @@ async fn main() -> Result<()> {
         }
         Commands::All => {
             println!("Running all tests...");
-            api_tests::run_all_tests().await?;
-            e2e_tests::run().await?;
-            load_tests::run_load();
+            let api = api_tests::run_suite(&api_tests::ApiTestOptions::default()).await;
+            let e2e = match start_driver() {
+                Ok(_driver) => e2e_tests::run_suite().await,
+                Err(e) => Err(e),
+            };
+            let e2e = match e2e {
+                Ok(report) => report.with_attachment_prefix("../crates/e2e_tests/reports"),
+                Err(e) => TestReport::new(
+                    "E2E Test Suite",
+                    vec![TestResult::ui_failed("E2E setup", "", 0, e.to_string())],
+                ),
+            };
+            let load = load_tests::run_suite();
+
+            let report = TestReport::merge("QA Test Suite", vec![api, e2e, load]);
+            ConsoleReporter::new().print(&report);
+            reporting::save_all(&report, Path::new("reports"), "combined_report")?;
+            if report.has_failures() {
+                bail!(
+                    "{} test(s) failed. Check reports for details.",
+                    report.failed
+                );
+            }
         }
         Commands::Config => {
             let cfg = get_config();